@@ -0,0 +1,570 @@
+use std::ops::Range;
+
+use crate::{AdminsError, AdminsErrorKind, SteamID, SteamIdFormat};
+
+/// How SourceMod recognises an admin, from the `auth` and `identity` of `admins.cfg`
+/// or the first column of `admins_simple.ini`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum AdminIdentity {
+    /// `auth "steam"`, or a Steam2, Steam3 or SteamID64 in `admins_simple.ini`.
+    Steam(SteamID),
+    /// `auth "ip"`, or an address after `!` in `admins_simple.ini`.
+    Ip(String),
+    /// `auth "name"`, or anything else in `admins_simple.ini`.
+    Name(String),
+    /// An auth method added by an extension.
+    Other { auth: String, identity: String },
+}
+
+/// An admin from a SourceMod admin file.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Admin {
+    name: Option<String>,
+    identity: AdminIdentity,
+    flags: String,
+    immunity: Option<u32>,
+    groups: Vec<String>,
+    password: Option<String>,
+}
+
+impl Admin {
+    /// The name of the admin's section in `admins.cfg`. None in `admins_simple.ini`.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// How SourceMod recognises the admin.
+    pub fn identity(&self) -> &AdminIdentity {
+        &self.identity
+    }
+
+    /// The admin's SteamID, if they're recognised by one.
+    pub fn steamid(&self) -> Option<SteamID> {
+        match self.identity {
+            AdminIdentity::Steam(steamid) => Some(steamid),
+            _ => None,
+        }
+    }
+
+    /// The admin's flag letters, such as `bcdef` or `z`. Can be empty.
+    pub fn flags(&self) -> &str {
+        &self.flags
+    }
+
+    /// The admin's immunity level, if it's set.
+    pub fn immunity(&self) -> Option<u32> {
+        self.immunity
+    }
+
+    /// The groups the admin is in, without the `@` of `admins_simple.ini`.
+    pub fn groups(&self) -> &[String] {
+        &self.groups
+    }
+
+    /// The password the admin has to set, if any.
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+}
+
+/// A SourceMod `admins_simple.ini` or `admins.cfg`.
+///
+/// The text of the file is kept, so it can be written back with its SteamIDs in another
+/// format and everything else, comments and layout included, as it was.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{AdminIdentity, AdminsFile, SteamIdFormat};
+///
+/// let text = r#"
+/// // Head admins
+/// "STEAM_0:1:221495335"   "99:z"              // Robin
+/// "[U:1:442990670]"       "@Full Admins"
+/// "76561198403256397"     "bcdef"     "hunter2"
+/// "!10.0.0.2"             "a"
+/// "12345"                 "a"
+/// "#;
+///
+/// let file = AdminsFile::parse_simple(text).unwrap();
+/// let admins = file.admins();
+///
+/// assert_eq!(admins.len(), 5);
+/// assert_eq!(admins[0].steamid().unwrap().steam64(), 76561198403256399);
+/// assert_eq!(admins[0].immunity(), Some(99));
+/// assert_eq!(admins[0].flags(), "z");
+/// assert_eq!(admins[1].groups(), ["Full Admins"]);
+/// assert_eq!(admins[2].password(), Some("hunter2"));
+/// assert_eq!(admins[3].steamid(), None);
+/// assert_eq!(admins[4].identity(), &AdminIdentity::Name(String::from("12345")));
+///
+/// assert_eq!(file.render(SteamIdFormat::Steam3 { instance: false }), r#"
+/// // Head admins
+/// "[U:1:442990671]"   "99:z"              // Robin
+/// "[U:1:442990670]"       "@Full Admins"
+/// "[U:1:442990669]"     "bcdef"     "hunter2"
+/// "!10.0.0.2"             "a"
+/// "12345"                 "a"
+/// "#);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct AdminsFile {
+    text: String,
+    admins: Vec<Admin>,
+    /// Where each admin's identity is in the text, quotes included.
+    identities: Vec<Range<usize>>,
+}
+
+impl AdminsFile {
+    /// Parses an `admins_simple.ini`, with one `"identity" "flags" "password"` line per admin.
+    ///
+    /// Identities starting with `STEAM_` or `[`, or made of 17 or more digits, are SteamIDs,
+    /// and have to parse as one. Shorter numbers are names. Flags can start with an immunity level and a colon, as in `99:z`,
+    /// and can be a group, as in `@Full Admins`. The password is optional.
+    pub fn parse_simple(text: &str) -> Result<AdminsFile, AdminsError> {
+        let tokens = tokenize(text)?;
+
+        let mut file = AdminsFile {
+            text: String::from(text),
+            admins: Vec::new(),
+            identities: Vec::new(),
+        };
+
+        for line in tokens.chunk_by(|a, b| a.line == b.line) {
+            let [identity, rest @ ..] = line else {
+                continue;
+            };
+
+            let error = |kind| AdminsError::new(kind, identity.line);
+
+            if let Some(token) = line.iter().find(|token| token.kind != TokenKind::String) {
+                return Err(AdminsError::new(
+                    AdminsErrorKind::UnexpectedToken,
+                    token.line,
+                ));
+            }
+
+            let (flags, password) = match rest {
+                [] => return Err(error(AdminsErrorKind::MissingFlags)),
+                [flags] => (flags, None),
+                [flags, password] => (flags, Some(password.value.clone())),
+                _ => return Err(error(AdminsErrorKind::UnexpectedToken)),
+            };
+
+            let (immunity, flags) = match flags.value.split_once(':') {
+                Some((immunity, flags)) => (Some(parse_immunity(immunity, identity.line)?), flags),
+                None => (None, flags.value.as_str()),
+            };
+
+            let (flags, groups) = match flags.strip_prefix('@') {
+                Some(group) => (String::new(), vec![String::from(group)]),
+                None => (String::from(flags), Vec::new()),
+            };
+
+            file.admins.push(Admin {
+                name: None,
+                identity: simple_identity(&identity.value).map_err(error)?,
+                flags,
+                immunity,
+                groups,
+                password,
+            });
+            file.identities.push(identity.span.clone());
+        }
+
+        Ok(file)
+    }
+
+    /// Parses an `admins.cfg`, with a section per admin in an `Admins` section.
+    ///
+    /// Identities with `auth "steam"` can be in any format [`SteamID::parse`] accepts.
+    /// An admin can have several `group` keys. Keys SourceMod doesn't know are skipped.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{AdminIdentity, AdminsFile, SteamIdFormat};
+    ///
+    /// let text = r#"Admins
+    /// {
+    ///     "Robin"
+    ///     {
+    ///         "auth"      "steam"
+    ///         "identity"  "76561198403256399"
+    ///         "group"     "Full Admins"
+    ///         "group"     "Mappers"
+    ///         "immunity"  "50"
+    ///     }
+    ///     "Ted"
+    ///     {
+    ///         "auth"      "name"
+    ///         "identity"  "Ted"
+    ///         "flags"     "abc"
+    ///         "password"  "hunter2"
+    ///     }
+    /// }
+    /// "#;
+    ///
+    /// let file = AdminsFile::parse_cfg(text).unwrap();
+    /// let admins = file.admins();
+    ///
+    /// assert_eq!(admins[0].name(), Some("Robin"));
+    /// assert_eq!(admins[0].groups(), ["Full Admins", "Mappers"]);
+    /// assert_eq!(admins[0].immunity(), Some(50));
+    /// assert_eq!(admins[1].identity(), &AdminIdentity::Name(String::from("Ted")));
+    /// assert_eq!(admins[1].flags(), "abc");
+    ///
+    /// let rendered = file.render(SteamIdFormat::Steam2 { public_as_zero: true, account_width: 0 });
+    /// assert!(rendered.contains(r#""identity"  "STEAM_0:1:221495335""#));
+    /// assert_eq!(AdminsFile::parse_cfg(&rendered).unwrap().admins(), admins);
+    /// ```
+    ///
+    /// Blocks inside an admin's section are skipped:
+    ///
+    /// ```
+    /// use scream_id::AdminsFile;
+    ///
+    /// let text = r#"Admins { "Robin" { "extra" { "key" "value" } "auth" "steam" "identity" "[U:1:442990671]" } }"#;
+    /// let file = AdminsFile::parse_cfg(text).unwrap();
+    ///
+    /// assert_eq!(file.admins().len(), 1);
+    /// assert_eq!(file.admins()[0].steamid().unwrap().steam64(), 76561198403256399);
+    /// ```
+    pub fn parse_cfg(text: &str) -> Result<AdminsFile, AdminsError> {
+        let mut tokens = tokenize(text)?.into_iter();
+
+        let mut file = AdminsFile {
+            text: String::from(text),
+            admins: Vec::new(),
+            identities: Vec::new(),
+        };
+
+        let mut depth = 0;
+        let mut in_admins = false;
+        let mut section: Option<Section> = None;
+
+        while let Some(token) = tokens.next() {
+            let unexpected =
+                |token: &Token| AdminsError::new(AdminsErrorKind::UnexpectedToken, token.line);
+
+            match token.kind {
+                TokenKind::Open => return Err(unexpected(&token)),
+                TokenKind::Close if depth == 0 => return Err(unexpected(&token)),
+                TokenKind::Close => {
+                    if depth == 2 {
+                        if let Some(section) = section.take() {
+                            let (admin, identity) = section.finish()?;
+                            file.admins.push(admin);
+                            file.identities.push(identity);
+                        }
+                    }
+
+                    depth -= 1;
+                }
+                TokenKind::String => {
+                    let Some(next) = tokens.next() else {
+                        return Err(AdminsError::new(AdminsErrorKind::UnexpectedEnd, token.line));
+                    };
+
+                    match next.kind {
+                        TokenKind::Open => {
+                            depth += 1;
+
+                            if depth == 1 {
+                                in_admins = token.value.eq_ignore_ascii_case("Admins");
+                            } else if depth == 2 && in_admins {
+                                section = Some(Section::new(token));
+                            }
+                        }
+                        TokenKind::String if depth == 2 => {
+                            if let Some(section) = &mut section {
+                                section.set(&token, next)?;
+                            }
+                        }
+                        TokenKind::String => {}
+                        TokenKind::Close => return Err(unexpected(&next)),
+                    }
+                }
+            }
+        }
+
+        if depth != 0 {
+            let line = text.lines().count().max(1);
+            return Err(AdminsError::new(AdminsErrorKind::UnexpectedEnd, line));
+        }
+
+        Ok(file)
+    }
+
+    /// The admins, in the order they're in the file.
+    pub fn admins(&self) -> &[Admin] {
+        &self.admins
+    }
+
+    /// Writes the file back with every Steam identity in the given format.
+    /// Everything else is left as it was.
+    ///
+    /// Identities keep their quotes, or lack of them. An unquoted identity is only quoted
+    /// when the new format needs it, like the `//` of a URL.
+    ///
+    /// SteamIDs that can't be written in the format, such as a clan in Steam2,
+    /// are left as they were too.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{AdminsFile, SteamIdFormat};
+    ///
+    /// let file = AdminsFile::parse_simple("STEAM_0:1:221495335 \"z\"").unwrap();
+    ///
+    /// assert_eq!(file.render(SteamIdFormat::Steam3 { instance: false }), "[U:1:442990671] \"z\"");
+    /// assert_eq!(
+    ///     file.render(SteamIdFormat::ProfileUrl),
+    ///     "\"https://steamcommunity.com/profiles/76561198403256399\" \"z\""
+    /// );
+    /// ```
+    pub fn render(&self, format: SteamIdFormat) -> String {
+        let mut output = String::with_capacity(self.text.len());
+        let mut end = 0;
+
+        for (admin, span) in self.admins.iter().zip(&self.identities) {
+            let Some(rendered) = admin.steamid().and_then(|steamid| steamid.render(format)) else {
+                continue;
+            };
+
+            let quote = self.text[span.clone()].starts_with('"') || needs_quotes(&rendered);
+
+            output.push_str(&self.text[end..span.start]);
+
+            if quote {
+                output.push('"');
+            }
+
+            output.push_str(&rendered);
+
+            if quote {
+                output.push('"');
+            }
+
+            end = span.end;
+        }
+
+        output.push_str(&self.text[end..]);
+        output
+    }
+}
+
+/// Returns true if `value` would be split up or cut short without quotes.
+fn needs_quotes(value: &str) -> bool {
+    value.is_empty()
+        || value.contains("//")
+        || value.contains(|c: char| c.is_ascii_whitespace() || "\"{}".contains(c))
+}
+
+/// Reads the identity column of `admins_simple.ini`.
+fn simple_identity(identity: &str) -> Result<AdminIdentity, AdminsErrorKind> {
+    if let Some(address) = identity.strip_prefix('!') {
+        return Ok(AdminIdentity::Ip(String::from(address)));
+    }
+
+    let is_steamid = identity.starts_with("STEAM_")
+        || identity.starts_with('[')
+        || (identity.len() >= 17 && identity.bytes().all(|b| b.is_ascii_digit()));
+
+    match is_steamid {
+        true => SteamID::parse(identity)
+            .map(AdminIdentity::Steam)
+            .map_err(AdminsErrorKind::SteamId),
+        false => Ok(AdminIdentity::Name(String::from(identity))),
+    }
+}
+
+fn parse_immunity(immunity: &str, line: usize) -> Result<u32, AdminsError> {
+    immunity
+        .trim()
+        .parse()
+        .map_err(|_| AdminsError::new(AdminsErrorKind::InvalidImmunity, line))
+}
+
+/// An admin's section of `admins.cfg`, as its keys are read.
+struct Section {
+    name: String,
+    line: usize,
+    auth: Option<String>,
+    identity: Option<Token>,
+    admin: Admin,
+}
+
+impl Section {
+    fn new(name: Token) -> Self {
+        Section {
+            name: name.value,
+            line: name.line,
+            auth: None,
+            identity: None,
+            admin: Admin {
+                name: None,
+                identity: AdminIdentity::Name(String::new()),
+                flags: String::new(),
+                immunity: None,
+                groups: Vec::new(),
+                password: None,
+            },
+        }
+    }
+
+    fn set(&mut self, key: &Token, value: Token) -> Result<(), AdminsError> {
+        let admin = &mut self.admin;
+
+        match key.value.to_ascii_lowercase().as_str() {
+            "auth" => self.auth = Some(value.value),
+            "identity" => self.identity = Some(value),
+            "flags" => admin.flags = value.value,
+            "immunity" => admin.immunity = Some(parse_immunity(&value.value, value.line)?),
+            "group" => admin.groups.push(value.value),
+            "password" => admin.password = Some(value.value),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Returns the admin and where its identity is.
+    fn finish(self) -> Result<(Admin, Range<usize>), AdminsError> {
+        let (Some(auth), Some(identity)) = (self.auth, self.identity) else {
+            return Err(AdminsError::new(
+                AdminsErrorKind::MissingIdentity,
+                self.line,
+            ));
+        };
+
+        let mut admin = self.admin;
+        admin.name = Some(self.name);
+        admin.identity = match auth.to_ascii_lowercase().as_str() {
+            "steam" => SteamID::parse(identity.value.trim())
+                .map(AdminIdentity::Steam)
+                .map_err(|error| {
+                    AdminsError::new(AdminsErrorKind::SteamId(error), identity.line)
+                })?,
+            "ip" => AdminIdentity::Ip(identity.value),
+            "name" => AdminIdentity::Name(identity.value),
+            _ => AdminIdentity::Other {
+                auth,
+                identity: identity.value,
+            },
+        };
+
+        Ok((admin, identity.span))
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum TokenKind {
+    String,
+    Open,
+    Close,
+}
+
+/// A string, `{` or `}` in a SourceMod config file.
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    /// The string without its quotes and escapes. Empty for braces.
+    value: String,
+    span: Range<usize>,
+    line: usize,
+}
+
+/// Splits a SourceMod config file into tokens, skipping whitespace and
+/// `//` and `/* */` comments.
+fn tokenize(text: &str) -> Result<Vec<Token>, AdminsError> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+
+        let kind = match bytes[i] {
+            b'\n' => {
+                line += 1;
+                i += 1;
+                continue;
+            }
+            b if b.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = text[i..].find('\n').map_or(text.len(), |end| i + end);
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = text[i + 2..]
+                    .find("*/")
+                    .map_or(text.len(), |end| i + end + 4);
+                line += text[start..i].matches('\n').count();
+                continue;
+            }
+            b'{' => TokenKind::Open,
+            b'}' => TokenKind::Close,
+            _ => TokenKind::String,
+        };
+
+        let value = match kind {
+            TokenKind::String if bytes[i] == b'"' => {
+                let (value, end) = unquote(&text[i + 1..])
+                    .ok_or_else(|| AdminsError::new(AdminsErrorKind::UnterminatedString, line))?;
+                i += end + 2;
+                value
+            }
+            TokenKind::String => {
+                let rest = &text[i..];
+                i += rest
+                    .find(|c: char| c.is_ascii_whitespace() || "\"{}".contains(c))
+                    .into_iter()
+                    .chain(rest.find("//"))
+                    .min()
+                    .unwrap_or(rest.len());
+                String::from(&text[start..i])
+            }
+            _ => {
+                i += 1;
+                String::new()
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            value,
+            span: start..i,
+            line,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Reads a quoted string up to its closing quote, which has to be on the same line.
+/// Returns the string and where the closing quote is.
+fn unquote(input: &str) -> Option<(String, usize)> {
+    let mut value = String::new();
+    let mut chars = input.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, i)),
+            '\n' => return None,
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                '\n' => return None,
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+
+    None
+}
@@ -0,0 +1,282 @@
+//! Rewrites the SteamIDs in one column of a CSV file or one field of a JSONL stream.
+
+use std::{
+    error::Error,
+    io::{self, BufRead, Read, Write},
+};
+
+use scream_id::{ParseOptions, SteamID};
+use serde_json::Value;
+
+use crate::Format;
+
+/// Converts one SteamID, or explains why it can't be.
+fn convert(input: &str, format: &Format, options: &ParseOptions) -> Result<String, String> {
+    let steamid = SteamID::parse_with(input, options).map_err(|error| error.to_string())?;
+
+    (format.render)(&steamid).ok_or_else(|| format!("can't be written as {}", format.name))
+}
+
+/// Reports a row that failed to `errors`. The row itself is written out unchanged.
+fn report(errors: &mut impl Write, line: u64, input: &str, message: &str) -> io::Result<()> {
+    writeln!(errors, "scream-id: line {}: {}: {}", line, input, message)
+}
+
+/// Rewrites `column` of a CSV file with a header row. Empty cells are left alone.
+/// Rows that fail are reported to `errors`. Returns false if any row failed.
+pub fn convert_csv(
+    input: impl Read,
+    output: impl Write,
+    mut errors: impl Write,
+    column: &str,
+    format: &Format,
+    options: &ParseOptions,
+) -> Result<bool, Box<dyn Error>> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(input);
+    let mut writer = csv::WriterBuilder::new().flexible(true).from_writer(output);
+
+    let headers = reader.headers()?.clone();
+    let Some(index) = headers.iter().position(|header| header == column) else {
+        return Err(format!("no column named '{}'", column).into());
+    };
+
+    writer.write_record(&headers)?;
+
+    let mut ok = true;
+
+    for record in reader.records() {
+        let record = record?;
+        let line = record.position().map_or(0, |position| position.line());
+
+        let converted = match record.get(index) {
+            None => None,
+            Some(cell) if cell.trim().is_empty() => None,
+            Some(cell) => match convert(cell, format, options) {
+                Ok(converted) => Some(converted),
+                Err(message) => {
+                    report(&mut errors, line, cell, &message)?;
+                    ok = false;
+                    None
+                }
+            },
+        };
+
+        match converted {
+            Some(converted) => {
+                writer.write_record(record.iter().enumerate().map(|(i, cell)| {
+                    match i == index {
+                        true => converted.as_str(),
+                        false => cell,
+                    }
+                }))?
+            }
+            None => writer.write_record(&record)?,
+        }
+    }
+
+    writer.flush()?;
+    Ok(ok)
+}
+
+/// Rewrites `field` of every object in a JSONL stream. The field can hold a string
+/// or a SteamID64 number, and is always written back as a string.
+/// Lines that aren't JSON objects with the field are reported to `errors` and written out
+/// unchanged. Returns false if any line failed.
+pub fn convert_jsonl(
+    input: impl BufRead,
+    mut output: impl Write,
+    mut errors: impl Write,
+    field: &str,
+    format: &Format,
+    options: &ParseOptions,
+) -> Result<bool, Box<dyn Error>> {
+    let mut ok = true;
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        let number = i as u64 + 1;
+
+        if line.trim().is_empty() {
+            writeln!(output, "{}", line)?;
+            continue;
+        }
+
+        match convert_json_line(&line, field, format, options) {
+            Ok(converted) => writeln!(output, "{}", converted)?,
+            Err((input, message)) => {
+                report(&mut errors, number, &input, &message)?;
+                writeln!(output, "{}", line)?;
+                ok = false;
+            }
+        }
+    }
+
+    output.flush()?;
+    Ok(ok)
+}
+
+/// Converts the field of one JSONL line. Fails with the offending input and a message.
+fn convert_json_line(
+    line: &str,
+    field: &str,
+    format: &Format,
+    options: &ParseOptions,
+) -> Result<String, (String, String)> {
+    let mut value: Value =
+        serde_json::from_str(line).map_err(|error| (String::from(line), error.to_string()))?;
+
+    let Some(id) = value.get_mut(field) else {
+        return Err((String::from(line), format!("no field named '{}'", field)));
+    };
+
+    let input = match id {
+        Value::String(id) => id.clone(),
+        Value::Number(id) => id.to_string(),
+        other => {
+            return Err((
+                other.to_string(),
+                String::from("expected a string or number"),
+            ))
+        }
+    };
+
+    *id = Value::String(convert(&input, format, options).map_err(|message| (input, message))?);
+
+    Ok(value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FORMATS;
+
+    fn format(name: &str) -> &'static Format {
+        FORMATS.iter().find(|format| format.name == name).unwrap()
+    }
+
+    /// Converts a CSV file, returning whether every row worked, the output and the errors.
+    fn csv(input: &str, column: &str, to: &str) -> Result<(bool, String, String), Box<dyn Error>> {
+        let (mut output, mut errors) = (Vec::new(), Vec::new());
+        let options = ParseOptions::new().trim(true);
+        let ok = convert_csv(
+            input.as_bytes(),
+            &mut output,
+            &mut errors,
+            column,
+            format(to),
+            &options,
+        )?;
+
+        Ok((ok, String::from_utf8(output)?, String::from_utf8(errors)?))
+    }
+
+    /// Converts a JSONL stream, returning whether every line worked, the output and the errors.
+    fn jsonl(input: &str, field: &str, to: &str) -> (bool, String, String) {
+        let (mut output, mut errors) = (Vec::new(), Vec::new());
+        let options = ParseOptions::new().trim(true);
+        let ok = convert_jsonl(
+            input.as_bytes(),
+            &mut output,
+            &mut errors,
+            field,
+            format(to),
+            &options,
+        )
+        .unwrap();
+
+        (
+            ok,
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(errors).unwrap(),
+        )
+    }
+
+    #[test]
+    fn csv_column_is_rewritten() {
+        let input = "name,steamid\nRobin,STEAM_0:1:221495335\nTed,[U:1:22202]\n";
+
+        assert_eq!(
+            csv(input, "steamid", "steam64").unwrap(),
+            (
+                true,
+                String::from("name,steamid\nRobin,76561198403256399\nTed,76561197960287930\n"),
+                String::new()
+            )
+        );
+    }
+
+    #[test]
+    fn csv_failures_are_reported_and_kept() {
+        let input = "name,steamid\nRobin,STEAM_0:1:221495335\nTed,nobody\nAlex,[g:1:4]\n";
+
+        assert_eq!(
+            csv(input, "steamid", "steam2").unwrap(),
+            (
+                false,
+                String::from("name,steamid\nRobin,STEAM_0:1:221495335\nTed,nobody\nAlex,[g:1:4]\n"),
+                String::from(
+                    "scream-id: line 3: nobody: unknown SteamID format at 0..6\n\
+                     scream-id: line 4: [g:1:4]: can't be written as steam2\n"
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn csv_empty_cells_are_left_alone() {
+        let input = "name,steamid,notes\nRobin,,x\nTed,  \nAlex\n";
+
+        assert_eq!(
+            csv(input, "steamid", "steam3").unwrap(),
+            (true, String::from(input), String::new())
+        );
+    }
+
+    #[test]
+    fn csv_missing_column_fails() {
+        let error = csv("name,steamid\n", "player", "steam3").unwrap_err();
+
+        assert_eq!(error.to_string(), "no column named 'player'");
+    }
+
+    #[test]
+    fn jsonl_field_is_rewritten() {
+        let input =
+            "{\"player\":76561198403256399,\"kills\":3}\n\n{\"player\":\"STEAM_0:1:221495335\"}\n";
+
+        assert_eq!(
+            jsonl(input, "player", "steam3"),
+            (
+                true,
+                String::from(
+                    "{\"player\":\"[U:1:442990671]\",\"kills\":3}\n\n{\"player\":\"[U:1:442990671]\"}\n"
+                ),
+                String::new()
+            )
+        );
+    }
+
+    #[test]
+    fn jsonl_failures_are_reported_and_kept() {
+        let input =
+            "{\"player\":\"[U:1:442990671]\"}\n{\"name\":\"Ted\"}\n{\"player\":true}\nnot json\n";
+        let (ok, output, errors) = jsonl(input, "player", "steam64");
+
+        assert!(!ok);
+        assert_eq!(
+            output,
+            "{\"player\":\"76561198403256399\"}\n{\"name\":\"Ted\"}\n{\"player\":true}\nnot json\n"
+        );
+
+        let errors: Vec<_> = errors.lines().collect();
+        assert_eq!(
+            errors[0],
+            "scream-id: line 2: {\"name\":\"Ted\"}: no field named 'player'"
+        );
+        assert_eq!(
+            errors[1],
+            "scream-id: line 3: true: expected a string or number"
+        );
+        assert!(errors[2].starts_with("scream-id: line 4: not json: "));
+    }
+}
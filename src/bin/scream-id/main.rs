@@ -0,0 +1,423 @@
+//! The `scream-id` command line tool, which converts SteamIDs between formats.
+
+mod bulk;
+
+use std::{
+    env,
+    error::Error,
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+use scream_id::{ParseOptions, SteamID};
+use serde_json::{json, Map, Value};
+
+const USAGE: &str = "\
+Usage: scream-id [OPTIONS] [ID]...
+       scream-id --csv <COLUMN> --format <FORMAT> < input.csv
+       scream-id --jsonl <FIELD> --format <FORMAT> < input.jsonl
+
+Converts SteamIDs between formats. Reads IDs from stdin, one per line, when none are given.
+
+With --csv or --jsonl, reads a CSV file or a JSONL stream from stdin and writes it to stdout
+with the SteamIDs in one column or field converted. Rows that fail are reported with their
+line number and written out unchanged.
+
+Options:
+  -f, --format <FORMAT>   Print only one format: steam64, steam2, steam3, invite,
+                          profile, group or csgo
+  -j, --json              Print a JSON object per ID
+      --csv <COLUMN>      Convert a column of a CSV file with a header row
+      --jsonl <FIELD>     Convert a field of every line of a JSONL stream
+
+Parse options, where the others adjust --lenient or --strict wherever they're given:
+  -l, --lenient           Ignore case in prefixes and URLs, and whitespace around IDs
+  -s, --strict            Only accept SteamID64, Steam2 and Steam3 IDs in the public universe
+      --case-insensitive  Ignore case in prefixes and URLs
+      --no-leading-zeros  Reject numbers that start with a zero
+      --zero-account-id   Accept SteamIDs with a zero account id
+      --no-trim           Don't ignore whitespace around IDs
+
+  -h, --help              Print this help";
+
+/// A way to write a SteamID that the tool can print.
+struct Format {
+    /// The name used by `--format` and as the JSON key.
+    name: &'static str,
+    /// The name shown in the table.
+    label: &'static str,
+    render: fn(&SteamID) -> Option<String>,
+}
+
+const FORMATS: &[Format] = &[
+    Format {
+        name: "steam64",
+        label: "SteamID64",
+        render: |steamid| Some(steamid.steam64().to_string()),
+    },
+    Format {
+        name: "steam2",
+        label: "Steam2",
+        render: SteamID::render_as_steam2,
+    },
+    Format {
+        name: "steam3",
+        label: "Steam3",
+        render: |steamid| Some(steamid.render_as_steam3()),
+    },
+    Format {
+        name: "invite",
+        label: "Invite code",
+        render: SteamID::to_invite_code,
+    },
+    Format {
+        name: "profile",
+        label: "Profile URL",
+        render: SteamID::profile_url,
+    },
+    Format {
+        name: "group",
+        label: "Group URL",
+        render: SteamID::group_url,
+    },
+    Format {
+        name: "csgo",
+        label: "CS:GO code",
+        render: SteamID::to_csgo_friend_code,
+    },
+];
+
+enum Output {
+    Table,
+    Json,
+    Single(&'static Format),
+}
+
+/// A file to rewrite instead of converting IDs one by one.
+enum Bulk {
+    Csv(String),
+    Jsonl(String),
+}
+
+struct Args {
+    output: Output,
+    bulk: Option<Bulk>,
+    options: ParseOptions,
+    ids: Vec<String>,
+    help: bool,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("scream-id: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.help {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("scream-id: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        output: Output::Table,
+        // Lines from stdin and pasted arguments often carry stray whitespace.
+        options: ParseOptions::new().trim(true),
+        bulk: None,
+        ids: Vec::new(),
+        help: false,
+    };
+
+    // The flags that change one option, applied after --lenient or --strict.
+    let mut adjustments: Vec<fn(ParseOptions) -> ParseOptions> = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let (flag, value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(String::from(value))),
+            _ => (arg.as_str(), None),
+        };
+
+        match flag {
+            "-f" | "--format" => {
+                let Some(name) = value.or_else(|| args.next()) else {
+                    return Err(format!("{} needs a format", flag));
+                };
+                let Some(format) = FORMATS.iter().find(|format| format.name == name) else {
+                    return Err(format!("unknown format '{}'", name));
+                };
+                parsed.output = Output::Single(format);
+            }
+            "-j" | "--json" => parsed.output = Output::Json,
+            "--csv" | "--jsonl" => {
+                let Some(name) = value.or_else(|| args.next()) else {
+                    return Err(format!("{} needs a column name", flag));
+                };
+                parsed.bulk = Some(match flag {
+                    "--csv" => Bulk::Csv(name),
+                    _ => Bulk::Jsonl(name),
+                });
+            }
+            "-l" | "--lenient" => parsed.options = ParseOptions::lenient(),
+            "-s" | "--strict" => parsed.options = ParseOptions::strict().trim(true),
+            "--case-insensitive" => adjustments.push(|options| options.case_insensitive(true)),
+            "--no-leading-zeros" => adjustments.push(|options| options.leading_zeros(false)),
+            "--zero-account-id" => adjustments.push(|options| options.zero_account_id(true)),
+            "--no-trim" => adjustments.push(|options| options.trim(false)),
+            "-h" | "--help" => parsed.help = true,
+            "--" => parsed.ids.extend(args.by_ref()),
+            flag if flag.starts_with('-') && flag.len() > 1 => {
+                return Err(format!("unknown option '{}'", flag));
+            }
+            _ => parsed.ids.push(arg),
+        }
+    }
+
+    for adjust in adjustments {
+        parsed.options = adjust(parsed.options);
+    }
+
+    if parsed.bulk.is_some() {
+        if !matches!(parsed.output, Output::Single(_)) {
+            return Err(String::from(
+                "--csv and --jsonl need a --format to convert to",
+            ));
+        }
+
+        if !parsed.ids.is_empty() {
+            return Err(String::from(
+                "--csv and --jsonl read from stdin, not arguments",
+            ));
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Converts every ID. Returns false if any of them failed.
+fn run(args: &Args) -> Result<bool, Box<dyn Error>> {
+    let mut out = io::stdout().lock();
+    let mut ok = true;
+
+    match (&args.bulk, &args.output) {
+        (Some(Bulk::Csv(column)), Output::Single(format)) => {
+            return bulk::convert_csv(
+                io::stdin().lock(),
+                out,
+                io::stderr(),
+                column,
+                format,
+                &args.options,
+            );
+        }
+        (Some(Bulk::Jsonl(field)), Output::Single(format)) => {
+            return bulk::convert_jsonl(
+                io::stdin().lock(),
+                out,
+                io::stderr(),
+                field,
+                format,
+                &args.options,
+            );
+        }
+        _ => {}
+    }
+
+    if args.ids.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let line = line?;
+
+            if !line.trim().is_empty() {
+                ok &= convert(&line, args, &mut out)?;
+            }
+        }
+    } else {
+        for id in &args.ids {
+            ok &= convert(id, args, &mut out)?;
+        }
+    }
+
+    Ok(ok)
+}
+
+/// Prints one ID in the chosen output. Returns false if it couldn't be converted.
+fn convert(input: &str, args: &Args, out: &mut impl Write) -> io::Result<bool> {
+    let steamid = match SteamID::parse_with(input, &args.options) {
+        Ok(steamid) => steamid,
+        Err(error) => return fail(input, &error.to_string(), args, out),
+    };
+
+    match args.output {
+        Output::Table => {
+            writeln!(out, "{:<13}{}", "Input", input.trim())?;
+
+            for format in FORMATS {
+                let rendered = (format.render)(&steamid);
+                writeln!(
+                    out,
+                    "{:<13}{}",
+                    format.label,
+                    rendered.as_deref().unwrap_or("-")
+                )?;
+            }
+
+            writeln!(out)?;
+        }
+        Output::Json => {
+            let mut object = Map::new();
+            object.insert(String::from("input"), Value::from(input.trim()));
+
+            for format in FORMATS {
+                object.insert(String::from(format.name), json!((format.render)(&steamid)));
+            }
+
+            writeln!(out, "{}", Value::Object(object))?;
+        }
+        Output::Single(format) => match (format.render)(&steamid) {
+            Some(rendered) => writeln!(out, "{}", rendered)?,
+            None => {
+                let message = format!("can't be written as {}", format.name);
+                return fail(input, &message, args, out);
+            }
+        },
+    }
+
+    Ok(true)
+}
+
+/// Reports an ID that couldn't be converted, in the JSON output or on stderr.
+fn fail(input: &str, message: &str, args: &Args, out: &mut impl Write) -> io::Result<bool> {
+    match args.output {
+        Output::Json => writeln!(
+            out,
+            "{}",
+            json!({ "input": input.trim(), "error": message })
+        )?,
+        _ => eprintln!("scream-id: {}: {}", input.trim(), message),
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|arg| String::from(*arg)))
+    }
+
+    /// Converts `input` with the given flags, returning whether it worked and the output.
+    fn run_convert(flags: &[&str], input: &str) -> (bool, String) {
+        let args = args(flags).unwrap();
+        let mut out = Vec::new();
+        let ok = convert(input, &args, &mut out).unwrap();
+
+        (ok, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn format_flag_selects_format() {
+        let input = "76561198403256399";
+
+        assert_eq!(
+            run_convert(&["-f", "steam3"], input),
+            (true, String::from("[U:1:442990671]\n"))
+        );
+        assert_eq!(
+            run_convert(&["--format=steam2"], input),
+            (true, String::from("STEAM_0:1:221495335\n"))
+        );
+        assert_eq!(
+            run_convert(&["--format", "invite"], input),
+            (true, String::from("cpjk-mbgw\n"))
+        );
+
+        assert!(args(&["--format", "steam4"]).is_err());
+        assert!(args(&["--format"]).is_err());
+    }
+
+    #[test]
+    fn table_lists_every_format() {
+        let (ok, out) = run_convert(&[], " STEAM_0:1:221495335 ");
+
+        assert!(ok);
+        assert!(out.starts_with("Input        STEAM_0:1:221495335\n"));
+        assert!(out.contains("SteamID64    76561198403256399\n"));
+        assert!(out.contains("Group URL    -\n"));
+    }
+
+    #[test]
+    fn invalid_input_fails() {
+        assert_eq!(
+            run_convert(&["-f", "steam64"], "STEAM_0:2:1"),
+            (false, String::new())
+        );
+        assert_eq!(run_convert(&[], "not a steamid"), (false, String::new()));
+
+        // A clan has no Steam2 ID.
+        assert_eq!(
+            run_convert(&["-f", "steam2"], "[g:1:4]"),
+            (false, String::new())
+        );
+    }
+
+    #[test]
+    fn json_reports_errors() {
+        let (ok, out) = run_convert(&["--json"], "76561198403256399");
+        let object: Value = serde_json::from_str(&out).unwrap();
+
+        assert!(ok);
+        assert_eq!(object["steam3"], "[U:1:442990671]");
+        assert_eq!(object["group"], Value::Null);
+
+        let (ok, out) = run_convert(&["-j"], "STEAM_0:2:1");
+        let object: Value = serde_json::from_str(&out).unwrap();
+
+        assert!(!ok);
+        assert_eq!(object["input"], "STEAM_0:2:1");
+        assert_eq!(object["error"], "parity bit must be 0 or 1 at 8..9");
+    }
+
+    #[test]
+    fn bulk_needs_format() {
+        assert!(args(&["--csv", "steamid"]).is_err());
+        assert!(args(&["--jsonl", "player", "--json"]).is_err());
+        assert!(args(&["--csv", "steamid", "-f", "steam64", "76561198403256399"]).is_err());
+
+        let parsed = args(&["--jsonl=player", "-f", "steam3"]).unwrap();
+        assert!(matches!(parsed.bulk, Some(Bulk::Jsonl(field)) if field == "player"));
+    }
+
+    #[test]
+    fn presets_keep_other_options() {
+        let zero_account_id = "STEAM_1:0:0";
+
+        assert!(!run_convert(&["--strict"], zero_account_id).0);
+        assert!(run_convert(&["--zero-account-id", "--strict"], zero_account_id).0);
+        assert!(run_convert(&["--strict", "--zero-account-id"], zero_account_id).0);
+
+        assert!(run_convert(&["-l"], " steam_0:1:221495335").0);
+        assert!(!run_convert(&["--no-trim", "--lenient"], " steam_0:1:221495335").0);
+    }
+
+    #[test]
+    fn unknown_options_are_rejected() {
+        assert!(args(&["--bogus"]).is_err());
+
+        let parsed = args(&["--", "-1"]).unwrap();
+        assert_eq!(parsed.ids, ["-1"]);
+    }
+}
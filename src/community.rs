@@ -0,0 +1,303 @@
+use std::ops::Range;
+
+use crate::{
+    equals,
+    invite::{decode_invite_code, strip_invite_url},
+    split_once, strip_prefix, take_until, ParseError, ParseErrorKind, ParseOptions, SteamID, Type,
+};
+
+const COMMUNITY_URL: &str = "https://steamcommunity.com";
+
+/// The characters that end the ID, name or invite code in a URL's path.
+pub(crate) const PATH_END: &[u8] = b"/?#";
+
+/// A Steam Community profile or group URL, or an `s.team/p/` friend invite link.
+///
+/// URLs with a SteamID in them, like `/profiles/76561198403256399` and `/gid/103582791429521412`,
+/// parse straight to a [`SteamID`]. Vanity URLs like `/id/gabelogannewell` and `/groups/valve`
+/// only have a name, which has to be resolved through the Steam Web API.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{CommunityUrl, SteamID};
+///
+/// let url = CommunityUrl::parse("https://steamcommunity.com/profiles/76561198403256399/").unwrap();
+/// assert_eq!(url.steamid(), SteamID::new("76561198403256399"));
+///
+/// let url = CommunityUrl::parse("steamcommunity.com/id/gabelogannewell").unwrap();
+/// assert_eq!(url, CommunityUrl::ProfileVanity(String::from("gabelogannewell")));
+/// assert!(url.needs_resolution());
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum CommunityUrl {
+    /// A `/profiles/` URL.
+    Profile(SteamID),
+    /// A `/gid/` URL.
+    Group(SteamID),
+    /// An `s.team/p/` friend invite link.
+    Invite(SteamID),
+    /// An `/id/` URL with the vanity name of a profile.
+    ProfileVanity(String),
+    /// A `/groups/` URL with the vanity name of a group.
+    GroupVanity(String),
+}
+
+/// A [`CommunityUrl`] with the byte range of its vanity name instead of the name,
+/// so it can be parsed in a const fn.
+pub(crate) enum ParsedUrl {
+    Profile(SteamID),
+    Group(SteamID),
+    Invite(SteamID),
+    ProfileVanity(Range<usize>),
+    GroupVanity(Range<usize>),
+}
+
+impl CommunityUrl {
+    /// Parses a Steam Community URL.
+    ///
+    /// The scheme and `www.` are optional, and anything after the ID or name,
+    /// like `/inventory/` or `?l=english`, is ignored.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{CommunityUrl, ParseErrorKind};
+    ///
+    /// let url = CommunityUrl::parse("https://steamcommunity.com/gid/[g:1:4]").unwrap();
+    /// assert_eq!(url.steamid().unwrap().steam64(), 103582791429521412);
+    ///
+    /// let url = CommunityUrl::parse("https://s.team/p/cfbf-hwkc").unwrap();
+    /// assert_eq!(url.steamid().unwrap().steam64(), 76561198279253873);
+    ///
+    /// let url = CommunityUrl::parse("https://steamcommunity.com/groups/valve?l=english").unwrap();
+    /// assert_eq!(url, CommunityUrl::GroupVanity(String::from("valve")));
+    ///
+    /// let error = CommunityUrl::parse("https://example.com/profiles/76561198403256399").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::UnknownPrefix);
+    /// ```
+    ///
+    /// `/profiles/` URLs have to hold an individual account, and `/gid/` URLs a clan:
+    ///
+    /// ```
+    /// use scream_id::{CommunityUrl, ParseErrorKind, SteamID};
+    ///
+    /// let error = CommunityUrl::parse("https://steamcommunity.com/gid/76561198403256399").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::TypeNotAllowed);
+    /// assert_eq!(error.span(), 31..48);
+    ///
+    /// let error = SteamID::parse("https://steamcommunity.com/profiles/103582791429521412").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::TypeNotAllowed);
+    /// assert_eq!(error.span(), 36..54);
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &ParseOptions::new())
+    }
+
+    /// Parses a Steam Community URL with the given options.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{CommunityUrl, ParseOptions};
+    ///
+    /// let options = ParseOptions::lenient();
+    /// let url = CommunityUrl::parse_with(" HTTPS://STEAMCOMMUNITY.COM/id/Robin ", &options).unwrap();
+    ///
+    /// assert_eq!(url, CommunityUrl::ProfileVanity(String::from("Robin")));
+    ///
+    /// let url = CommunityUrl::parse_with("HTTPS://S.TEAM/P/CFBF-HWKC", &options).unwrap();
+    /// assert_eq!(url.steamid().unwrap().steam64(), 76561198279253873);
+    /// ```
+    pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let (start, input) = match options.trim {
+            true => (input.len() - input.trim_start().len(), input.trim()),
+            false => (0, input),
+        };
+
+        let url = match Self::parse_untrimmed(input.as_bytes(), options) {
+            Ok(url) => url,
+            Err(error) => return Err(error.offset(start)),
+        };
+
+        Ok(match url {
+            ParsedUrl::Profile(steamid) => CommunityUrl::Profile(steamid),
+            ParsedUrl::Group(steamid) => CommunityUrl::Group(steamid),
+            ParsedUrl::Invite(steamid) => CommunityUrl::Invite(steamid),
+            ParsedUrl::ProfileVanity(name) => {
+                CommunityUrl::ProfileVanity(String::from(&input[name]))
+            }
+            ParsedUrl::GroupVanity(name) => CommunityUrl::GroupVanity(String::from(&input[name])),
+        })
+    }
+
+    pub(crate) const fn parse_untrimmed(
+        input: &[u8],
+        options: &ParseOptions,
+    ) -> Result<ParsedUrl, ParseError> {
+        // EG: https://steamcommunity.com/profiles/76561198403256399
+
+        if let Some(code) = strip_invite_url(input, options) {
+            let start = input.len() - code.len();
+
+            return match decode_invite_code(take_until(code, PATH_END), options) {
+                Ok(steamid) => Ok(ParsedUrl::Invite(steamid)),
+                Err(error) => Err(error.offset(start)),
+            };
+        }
+
+        let url = strip_scheme(input, options);
+        let url = match strip_prefix(url, "www.", options) {
+            Some(url) => url,
+            None => url,
+        };
+
+        let Some(path) = strip_prefix(url, "steamcommunity.com/", options) else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnknownPrefix,
+                0..input.len(),
+            ));
+        };
+
+        let start = input.len() - path.len();
+        let (kind, value) = match split_once(path, b'/') {
+            Some(parts) => parts,
+            None => path.split_at(path.len()),
+        };
+        let value_start = start + kind.len() + 1;
+
+        // Whatever comes after the ID or name doesn't matter.
+        let value = take_until(value, PATH_END);
+        let value_span = value_start..value_start + value.len();
+
+        if value.is_empty() {
+            return Err(ParseError::new(
+                ParseErrorKind::WrongComponentCount,
+                start..input.len(),
+            ));
+        }
+
+        if equals(kind, "profiles", options) {
+            Ok(ParsedUrl::Profile(tri!(parse_path_id(
+                value,
+                value_start,
+                Type::Individual,
+                options
+            ))))
+        } else if equals(kind, "gid", options) {
+            Ok(ParsedUrl::Group(tri!(parse_path_id(
+                value,
+                value_start,
+                Type::Clan,
+                options
+            ))))
+        } else if equals(kind, "id", options) {
+            Ok(ParsedUrl::ProfileVanity(value_span))
+        } else if equals(kind, "groups", options) {
+            Ok(ParsedUrl::GroupVanity(value_span))
+        } else {
+            Err(ParseError::new(
+                ParseErrorKind::UnknownPrefix,
+                start..start + kind.len(),
+            ))
+        }
+    }
+
+    /// The SteamID in the URL, or None if it's a vanity URL.
+    pub fn steamid(&self) -> Option<SteamID> {
+        match self {
+            CommunityUrl::Profile(steamid)
+            | CommunityUrl::Group(steamid)
+            | CommunityUrl::Invite(steamid) => Some(*steamid),
+            CommunityUrl::ProfileVanity(_) | CommunityUrl::GroupVanity(_) => None,
+        }
+    }
+
+    /// Returns true for vanity URLs, which need to be resolved through the
+    /// Steam Web API to get a SteamID.
+    pub fn needs_resolution(&self) -> bool {
+        self.steamid().is_none()
+    }
+}
+
+/// Strips `https://` or `http://` from the start of a URL, if it has one.
+pub(crate) const fn strip_scheme<'a>(input: &'a [u8], options: &ParseOptions) -> &'a [u8] {
+    if let Some(url) = strip_prefix(input, "https://", options) {
+        url
+    } else if let Some(url) = strip_prefix(input, "http://", options) {
+        url
+    } else {
+        input
+    }
+}
+
+/// Parses the SteamID64 or Steam3 ID in a URL path, which starts at byte `start`.
+/// `/profiles/` only holds individual accounts and `/gid/` only holds clans.
+const fn parse_path_id(
+    value: &[u8],
+    start: usize,
+    expected: Type,
+    options: &ParseOptions,
+) -> Result<SteamID, ParseError> {
+    let steamid = match value {
+        [b'[', ..] => match SteamID::parse_steam3(value, options) {
+            Ok((steamid, _)) => Ok(steamid),
+            Err(error) => Err(error),
+        },
+        _ => match SteamID::parse_steam64(value, options) {
+            Ok(steam64) => Ok(SteamID::from_steam64(steam64)),
+            Err(error) => Err(error),
+        },
+    };
+
+    match steamid {
+        Ok(steamid) if steamid.account_type().as_u32() == expected.as_u32() => Ok(steamid),
+        Ok(_) => Err(ParseError::new(
+            ParseErrorKind::TypeNotAllowed,
+            start..start + value.len(),
+        )),
+        Err(error) => Err(error.offset(start)),
+    }
+}
+
+impl SteamID {
+    /// The Steam Community profile URL of an individual account.
+    /// Returns None for other types.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let steamid = scream_id::SteamID::new("STEAM_0:1:221495335").unwrap();
+    ///
+    /// assert_eq!(
+    ///     steamid.profile_url().unwrap(),
+    ///     "https://steamcommunity.com/profiles/76561198403256399"
+    /// );
+    /// ```
+    pub fn profile_url(&self) -> Option<String> {
+        match self.account_type() {
+            Type::Individual => Some(format!("{}/profiles/{}", COMMUNITY_URL, self.steam64())),
+            _ => None,
+        }
+    }
+
+    /// The Steam Community group URL of a clan.
+    /// Returns None for other types.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let steamid = scream_id::SteamID::new("[g:1:4]").unwrap();
+    ///
+    /// assert_eq!(
+    ///     steamid.group_url().unwrap(),
+    ///     "https://steamcommunity.com/gid/103582791429521412"
+    /// );
+    /// ```
+    pub fn group_url(&self) -> Option<String> {
+        match self.account_type() {
+            Type::Clan => Some(format!("{}/gid/{}", COMMUNITY_URL, self.steam64())),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{md5::md5, ParseError, ParseErrorKind, SteamID, Type, Universe};
+
+const ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Every friend code of an account id that fits in 32 bits starts with this.
+const PREFIX: &str = "AAAA-";
+
+impl SteamID {
+    /// The friend code Counter-Strike shows for an individual account in the public universe,
+    /// such as `SUCVS-FADA`. Returns None for other SteamIDs.
+    ///
+    /// The code interleaves the account id with bits of an MD5 hash of it,
+    /// written in a base32 alphabet without the look-alike characters.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// let table = [
+    ///     ("76561197960287930", "SUCVS-FADA"),
+    ///     ("76561198403256399", "A3HAH-SNGJ"),
+    ///     ("76561198279253873", "SPS9V-FBGQ"),
+    ///     ("76561197960265729", "AJJJS-ABAA"),
+    ///     ("76561202255233023", "S9ZZR-999P"),
+    /// ];
+    ///
+    /// for (steam64, code) in table {
+    ///     let steamid = SteamID::new(steam64).unwrap();
+    ///
+    ///     assert_eq!(steamid.to_csgo_friend_code().unwrap(), code);
+    ///     assert_eq!(SteamID::from_csgo_friend_code(code).unwrap(), steamid);
+    /// }
+    ///
+    /// assert_eq!(SteamID::new("[g:1:4]").unwrap().to_csgo_friend_code(), None);
+    /// ```
+    pub fn to_csgo_friend_code(&self) -> Option<String> {
+        if self.account_type() != Type::Individual || self.universe() != Universe::Public {
+            return None;
+        }
+
+        let code = encode(self.account_id());
+        Some(String::from(&code[PREFIX.len()..]))
+    }
+
+    /// Decodes a Counter-Strike friend code into the SteamID of an individual account
+    /// in the public universe. The `AAAA-` prefix is optional and case is ignored.
+    ///
+    /// Codes whose hash bits don't match the account id are rejected.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{ParseErrorKind, SteamID};
+    ///
+    /// let steamid = SteamID::from_csgo_friend_code("AAAA-SUCVS-FADA").unwrap();
+    /// assert_eq!(steamid.steam64(), 76561197960287930);
+    ///
+    /// let steamid = SteamID::from_csgo_friend_code("sucvs-fada").unwrap();
+    /// assert_eq!(steamid.steam64(), 76561197960287930);
+    ///
+    /// let error = SteamID::from_csgo_friend_code("SUCVS-FADB").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidChecksum);
+    ///
+    /// let error = SteamID::from_csgo_friend_code("SUCVS-FAD1").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidCharacter);
+    /// assert_eq!(error.span(), 9..10);
+    /// ```
+    pub fn from_csgo_friend_code(code: &str) -> Result<Self, ParseError> {
+        let start = match code.get(..PREFIX.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(PREFIX) => PREFIX.len(),
+            _ => 0,
+        };
+        let code = &code[start..];
+
+        // EG: SUCVS-FADA
+        if code.len() != 10 {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidLength,
+                start..start + code.len(),
+            ));
+        }
+
+        // The 13 letters of the full code hold 5 bits each, lowest bits first.
+        // The four letters of the prefix are all zero.
+        let mut result: u128 = 0;
+        for (i, letter) in code.bytes().enumerate() {
+            let span = start + i..start + i + 1;
+
+            if i == 5 {
+                if letter != b'-' {
+                    return Err(ParseError::new(ParseErrorKind::InvalidCharacter, span));
+                }
+                continue;
+            }
+
+            let letter = letter.to_ascii_uppercase();
+            let Some(value) = ALPHABET.iter().position(|&l| l == letter) else {
+                return Err(ParseError::new(ParseErrorKind::InvalidCharacter, span));
+            };
+
+            let shift = 5 * (4 + i - (i > 5) as usize);
+            result |= (value as u128) << shift;
+        }
+
+        let span = 0..start + code.len();
+        let Ok(result) = u64::try_from(result) else {
+            return Err(ParseError::new(ParseErrorKind::AccountIdOutOfRange, span));
+        };
+
+        // Every 5 bits hold a hash bit followed by a nibble of the account id.
+        let mut result = result.swap_bytes();
+        let mut account_id: u32 = 0;
+        for _ in 0..8 {
+            result >>= 1;
+            account_id = account_id << 4 | (result & 0xF) as u32;
+            result >>= 4;
+        }
+
+        if account_id == 0 {
+            return Err(ParseError::new(ParseErrorKind::ZeroAccountId, span));
+        }
+
+        if !encode(account_id)[PREFIX.len()..].eq_ignore_ascii_case(code) {
+            return Err(ParseError::new(ParseErrorKind::InvalidChecksum, span));
+        }
+
+        Ok(SteamID::from_individual_account_id(account_id))
+    }
+}
+
+/// Encodes an account id as a full friend code, `AAAA-` prefix included.
+fn encode(account_id: u32) -> String {
+    let mut hashed = [0; 8];
+    hashed[..4].copy_from_slice(&account_id.to_le_bytes());
+    hashed[4..].copy_from_slice(b"OGSC");
+
+    let digest = md5(&hashed);
+    let hash = u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]]);
+
+    let steam64 = SteamID::from_individual_account_id(account_id).steam64();
+    let mut result: u64 = 0;
+    for i in 0..8 {
+        let id_nibble = (steam64 >> (i * 4)) & 0xF;
+        let hash_bit = ((hash >> i) & 1) as u64;
+
+        let a = (result << 4) | id_nibble;
+        result = ((result >> 28) << 32) | a;
+        result = ((result >> 31) << 32) | ((a << 1) | hash_bit);
+    }
+
+    let mut result = result.swap_bytes();
+    let mut code = String::with_capacity(15);
+    for i in 0..13 {
+        if i == 4 || i == 9 {
+            code.push('-');
+        }
+
+        code.push(ALPHABET[(result & 31) as usize] as char);
+        result >>= 5;
+    }
+
+    code
+}
@@ -0,0 +1,301 @@
+use std::{error::Error, fmt, ops::Range};
+
+use crate::SteamID;
+
+/// The reason a SteamID failed to parse.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input was empty.
+    Empty,
+    /// The input doesn't start like any known SteamID format.
+    UnknownPrefix,
+    /// The input was too short or too long, like a SteamID64 without 17 to 20 digits.
+    InvalidLength,
+    /// A SteamID64 doesn't fit in 64 bits.
+    Overflow,
+    /// A numeric component contained something other than ASCII digits.
+    NonNumeric,
+    /// A character that isn't allowed, such as a letter outside the invite code alphabet.
+    InvalidCharacter,
+    /// The ID had the wrong number of `:` separated components.
+    WrongComponentCount,
+    /// A Steam3 ID was missing its closing `]`.
+    Unterminated,
+    /// A Steam3 ID used an unknown type letter.
+    UnknownType,
+    /// The universe doesn't fit in the 8 universe bits.
+    UniverseOutOfRange,
+    /// The instance doesn't fit in the 20 instance bits.
+    InstanceOutOfRange,
+    /// The `Y` in `STEAM_X:Y:Z` wasn't 0 or 1.
+    InvalidParityBit,
+    /// The account id doesn't fit in 32 bits.
+    AccountIdOutOfRange,
+    /// The account id was zero.
+    ZeroAccountId,
+    /// The check bits of a code don't match the account id it holds.
+    InvalidChecksum,
+    /// A vanity URL, which has to be resolved through the Steam Web API.
+    VanityUrl,
+    /// A number started with a zero and the options don't allow that.
+    LeadingZero,
+    /// The input is in a format the options don't allow.
+    FormatNotAllowed,
+    /// The SteamID is in a universe the options don't allow.
+    UniverseNotAllowed,
+    /// The SteamID is of a type the options don't allow, or that a URL can't hold,
+    /// like a clan in a `/profiles/` URL.
+    TypeNotAllowed,
+}
+
+impl ParseErrorKind {
+    /// A short description of the error, as shown by `Display`.
+    pub(crate) const fn message(self) -> &'static str {
+        match self {
+            ParseErrorKind::Empty => "empty input",
+            ParseErrorKind::UnknownPrefix => "unknown SteamID format",
+            ParseErrorKind::InvalidLength => "wrong length",
+            ParseErrorKind::Overflow => "SteamID64 doesn't fit in 64 bits",
+            ParseErrorKind::NonNumeric => "expected a number",
+            ParseErrorKind::InvalidCharacter => "invalid character",
+            ParseErrorKind::WrongComponentCount => "wrong number of components",
+            ParseErrorKind::Unterminated => "missing closing bracket",
+            ParseErrorKind::UnknownType => "unknown account type letter",
+            ParseErrorKind::UniverseOutOfRange => "universe out of range",
+            ParseErrorKind::InstanceOutOfRange => "instance out of range",
+            ParseErrorKind::InvalidParityBit => "parity bit must be 0 or 1",
+            ParseErrorKind::AccountIdOutOfRange => "account id out of range",
+            ParseErrorKind::ZeroAccountId => "account id is zero",
+            ParseErrorKind::InvalidChecksum => "checksum doesn't match",
+            ParseErrorKind::VanityUrl => "vanity URL needs to be resolved",
+            ParseErrorKind::LeadingZero => "number has a leading zero",
+            ParseErrorKind::FormatNotAllowed => "format not allowed",
+            ParseErrorKind::UniverseNotAllowed => "universe not allowed",
+            ParseErrorKind::TypeNotAllowed => "account type not allowed",
+        }
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// An error returned when a SteamID can't be parsed.
+///
+/// Carries the byte span of the part of the input that was rejected.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{ParseErrorKind, SteamID};
+///
+/// let error = SteamID::parse("STEAM_0:2:221495335").unwrap_err();
+///
+/// assert_eq!(error.kind(), ParseErrorKind::InvalidParityBit);
+/// assert_eq!(error.span(), 8..9);
+/// assert_eq!(error.to_string(), "parity bit must be 0 or 1 at 8..9");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    pub(crate) const fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// Moves the span along, for errors found in a slice of the real input.
+    pub(crate) const fn offset(mut self, by: usize) -> Self {
+        self.span = self.span.start + by..self.span.end + by;
+        self
+    }
+
+    /// Why the input was rejected.
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte range of the offending component in the input.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl Error for ParseError {}
+
+/// An error returned when a SteamID isn't the type of account that was needed,
+/// such as converting a clan into an [`IndividualId`](crate::IndividualId).
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{IndividualId, SteamID};
+///
+/// let steamid = SteamID::new("[g:1:4]").unwrap();
+/// let error = IndividualId::try_from(steamid).unwrap_err();
+///
+/// assert_eq!(error.steamid(), steamid);
+/// assert_eq!(error.to_string(), "[g:1:4] is not an individual account");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct WrongTypeError {
+    steamid: SteamID,
+    expected: &'static str,
+}
+
+impl WrongTypeError {
+    pub(crate) fn new(steamid: SteamID, expected: &'static str) -> Self {
+        Self { steamid, expected }
+    }
+
+    /// The SteamID that had the wrong type.
+    pub fn steamid(&self) -> SteamID {
+        self.steamid
+    }
+}
+
+impl fmt::Display for WrongTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#} is not {}", self.steamid, self.expected)
+    }
+}
+
+impl Error for WrongTypeError {}
+
+/// The error returned when parsing one of the typed IDs from a string fails.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum TypedParseError {
+    /// The input isn't a SteamID.
+    Parse(ParseError),
+    /// The input is a SteamID, but of the wrong type.
+    WrongType(WrongTypeError),
+}
+
+impl fmt::Display for TypedParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypedParseError::Parse(error) => error.fmt(f),
+            TypedParseError::WrongType(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for TypedParseError {}
+
+/// The rule a SteamID broke, returned by [`SteamID::validate`].
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The universe isn't between `Public` and `Dev`.
+    Universe,
+    /// The account type isn't between `Individual` and `AnonUser`.
+    Type,
+    /// An individual account, clan or game server has a zero account id.
+    ZeroAccountId,
+    /// An individual account has an instance above `Web`.
+    IndividualInstance,
+    /// A clan has an instance other than `All`.
+    ClanInstance,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ValidationError::Universe => "universe out of range",
+            ValidationError::Type => "account type out of range",
+            ValidationError::ZeroAccountId => "account id is zero",
+            ValidationError::IndividualInstance => "individual account instance out of range",
+            ValidationError::ClanInstance => "clan instance must be 0",
+        })
+    }
+}
+
+impl Error for ValidationError {}
+
+/// The error returned when reading a SourceMod admin file fails.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{AdminsErrorKind, AdminsFile, ParseErrorKind};
+///
+/// let error = AdminsFile::parse_simple("\"STEAM_0:1:221495335\" \"z\"\n\"STEAM_0:2:1\" \"z\"").unwrap_err();
+///
+/// assert_eq!(error.line(), 2);
+/// assert!(matches!(error.kind(), AdminsErrorKind::SteamId(e) if e.kind() == ParseErrorKind::InvalidParityBit));
+/// assert_eq!(error.to_string(), "line 2: parity bit must be 0 or 1 at 8..9");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AdminsError {
+    kind: AdminsErrorKind,
+    line: usize,
+}
+
+impl AdminsError {
+    pub(crate) fn new(kind: AdminsErrorKind, line: usize) -> Self {
+        Self { kind, line }
+    }
+
+    /// Why the file was rejected.
+    pub fn kind(&self) -> &AdminsErrorKind {
+        &self.kind
+    }
+
+    /// The line the problem is on, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for AdminsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl Error for AdminsError {}
+
+/// Why a SourceMod admin file was rejected, returned by [`AdminsError::kind`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum AdminsErrorKind {
+    /// A quoted string isn't closed before the end of its line.
+    UnterminatedString,
+    /// A `{`, `}` or value is somewhere it can't be.
+    UnexpectedToken,
+    /// The file ends inside a section, or with a key that has no value.
+    UnexpectedEnd,
+    /// A line of `admins_simple.ini` has an identity but no flags.
+    MissingFlags,
+    /// An admin in `admins.cfg` has no `auth` or no `identity`.
+    MissingIdentity,
+    /// The immunity level isn't a number.
+    InvalidImmunity,
+    /// A Steam identity isn't a SteamID.
+    SteamId(ParseError),
+}
+
+impl fmt::Display for AdminsErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AdminsErrorKind::UnterminatedString => "unterminated string",
+            AdminsErrorKind::UnexpectedToken => "unexpected token",
+            AdminsErrorKind::UnexpectedEnd => "unexpected end of file",
+            AdminsErrorKind::MissingFlags => "admin has no flags",
+            AdminsErrorKind::MissingIdentity => "admin has no auth or identity",
+            AdminsErrorKind::InvalidImmunity => "immunity must be a number",
+            AdminsErrorKind::SteamId(error) => return error.fmt(f),
+        })
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt;
+
+use crate::{ChatFlags, Instance, SteamID, SteamIdFormat, Type, Universe};
+
+/// A breakdown of the bit fields of a SteamID, from [`SteamID::explain`].
+///
+/// Its `Display` writes one field per line, for pasting into a support ticket.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{Anomaly, SteamID};
+///
+/// let explanation = SteamID::explain_steam64(680043565207781376);
+///
+/// assert_eq!(explanation.steamid().render_as_steam3(), "[g:9:0:5]");
+/// assert_eq!(
+///     explanation.anomalies(),
+///     [Anomaly::UnknownUniverse(9), Anomaly::UnexpectedInstance(5), Anomaly::ZeroAccountId]
+/// );
+///
+/// let text = explanation.to_string();
+///
+/// assert!(text.starts_with("Universe: unknown (9)\nType: Clan (7)\n"));
+/// assert!(text.contains("\nSteam3: [g:9:0:5]\n"));
+/// assert!(text.ends_with("Anomalies:\n- unknown universe 9\n- instance 5 doesn't match the account type\n- the account id is zero"));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Explanation {
+    steamid: SteamID,
+    renderings: Vec<(&'static str, String)>,
+    anomalies: Vec<Anomaly>,
+}
+
+/// Something odd about a SteamID, that Steam wouldn't issue.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Anomaly {
+    /// The universe is [`Universe::Invalid`].
+    InvalidUniverse,
+    /// The universe isn't one this crate knows about.
+    UnknownUniverse(u32),
+    /// The account type is [`Type::Invalid`].
+    InvalidType,
+    /// The account type isn't one this crate knows about.
+    UnknownType(u32),
+    /// The instance isn't one Steam gives this type of account.
+    UnexpectedInstance(u32),
+    /// The account id is zero.
+    ZeroAccountId,
+}
+
+impl SteamID {
+    /// Breaks the SteamID down into its fields, renders it in every format it can be
+    /// written in and points out anything odd about it.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let steamid = scream_id::SteamID::new("76561198403256399").unwrap();
+    /// let explanation = steamid.explain();
+    ///
+    /// assert!(explanation.anomalies().is_empty());
+    /// assert!(explanation.to_string().contains("STEAM_0:1:221495335"));
+    /// ```
+    pub fn explain(&self) -> Explanation {
+        let formats = [
+            ("SteamID64", SteamIdFormat::Steam64),
+            (
+                "Steam2",
+                SteamIdFormat::Steam2 {
+                    public_as_zero: true,
+                    account_width: 0,
+                },
+            ),
+            ("Steam3", SteamIdFormat::Steam3 { instance: false }),
+            ("Profile URL", SteamIdFormat::ProfileUrl),
+            ("Group URL", SteamIdFormat::GroupUrl),
+            ("Invite URL", SteamIdFormat::InviteUrl),
+        ];
+
+        let mut renderings: Vec<_> = formats
+            .into_iter()
+            .filter_map(|(name, format)| Some((name, self.render(format)?)))
+            .collect();
+
+        if let Some(code) = self.to_csgo_friend_code() {
+            renderings.push(("CS:GO friend code", code));
+        }
+
+        Explanation {
+            steamid: *self,
+            renderings,
+            anomalies: self.anomalies(),
+        }
+    }
+
+    /// Explains a raw SteamID64, including ones that don't parse, such as a zero account id.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{Anomaly, SteamID};
+    ///
+    /// let explanation = SteamID::explain_steam64(76561197960265728);
+    /// assert_eq!(explanation.anomalies(), [Anomaly::ZeroAccountId]);
+    /// ```
+    pub fn explain_steam64(steam64: u64) -> Explanation {
+        Self::from_steam64(steam64).explain()
+    }
+
+    fn anomalies(&self) -> Vec<Anomaly> {
+        let mut anomalies = Vec::new();
+
+        match self.universe() {
+            Universe::Invalid => anomalies.push(Anomaly::InvalidUniverse),
+            Universe::Other(universe) => anomalies.push(Anomaly::UnknownUniverse(universe)),
+            _ => {}
+        }
+
+        match self.account_type() {
+            Type::Invalid => anomalies.push(Anomaly::InvalidType),
+            Type::Other(type_) => anomalies.push(Anomaly::UnknownType(type_)),
+            _ => {}
+        }
+
+        let instance = self.instance();
+        let expected = match self.account_type() {
+            Type::Individual => instance.as_u32() <= Instance::Web.as_u32(),
+            Type::Clan | Type::Chat => instance == Instance::All,
+            _ => true,
+        };
+
+        if !expected {
+            anomalies.push(Anomaly::UnexpectedInstance(instance.as_u32()));
+        }
+
+        if self.account_id() == 0 {
+            anomalies.push(Anomaly::ZeroAccountId);
+        }
+
+        anomalies
+    }
+}
+
+impl Explanation {
+    /// The SteamID that was explained.
+    pub fn steamid(&self) -> SteamID {
+        self.steamid
+    }
+
+    /// The universe the SteamID belongs to.
+    pub fn universe(&self) -> Universe {
+        self.steamid.universe()
+    }
+
+    /// The type of account.
+    pub fn account_type(&self) -> Type {
+        self.steamid.account_type()
+    }
+
+    /// The instance, without the chat flags.
+    pub fn instance(&self) -> Instance {
+        self.steamid.instance()
+    }
+
+    /// The chat flags, empty unless the SteamID is a chat.
+    pub fn chat_flags(&self) -> ChatFlags {
+        self.steamid.chat_flags()
+    }
+
+    /// The 32-bit account id.
+    pub fn account_id(&self) -> u32 {
+        self.steamid.account_id()
+    }
+
+    /// The SteamID written in every format that applies to it, labelled with the format's name.
+    pub fn renderings(&self) -> &[(&'static str, String)] {
+        &self.renderings
+    }
+
+    /// Anything odd about the SteamID. Empty if Steam could have issued it.
+    pub fn anomalies(&self) -> &[Anomaly] {
+        &self.anomalies
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let universe = self.universe();
+        let account_type = self.account_type();
+        let instance = self.instance();
+
+        // `Other(9)` reads better as `unknown (9)`.
+        let name = |value: &dyn fmt::Debug, other: bool| match other {
+            true => String::from("unknown"),
+            false => format!("{:?}", value),
+        };
+
+        writeln!(
+            f,
+            "Universe: {} ({})",
+            name(&universe, matches!(universe, Universe::Other(_))),
+            universe.as_u32()
+        )?;
+        writeln!(
+            f,
+            "Type: {} ({})",
+            name(&account_type, matches!(account_type, Type::Other(_))),
+            account_type.as_u32()
+        )?;
+        writeln!(
+            f,
+            "Instance: {} ({})",
+            name(&instance, matches!(instance, Instance::Other(_))),
+            instance.as_u32()
+        )?;
+
+        let flags = [
+            ("clan", ChatFlags::CLAN),
+            ("lobby", ChatFlags::LOBBY),
+            ("matchmaking lobby", ChatFlags::MMS_LOBBY),
+        ];
+        let flags: Vec<_> = flags
+            .into_iter()
+            .filter(|(_, flag)| self.chat_flags().contains(*flag))
+            .map(|(name, _)| name)
+            .collect();
+
+        if !flags.is_empty() {
+            writeln!(f, "Chat flags: {}", flags.join(", "))?;
+        }
+
+        writeln!(f, "Account id: {}", self.account_id())?;
+
+        for (name, rendered) in &self.renderings {
+            writeln!(f, "{}: {}", name, rendered)?;
+        }
+
+        match self.anomalies.as_slice() {
+            [] => write!(f, "No anomalies"),
+            anomalies => {
+                write!(f, "Anomalies:")?;
+                anomalies
+                    .iter()
+                    .try_for_each(|anomaly| write!(f, "\n- {}", anomaly))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Anomaly::InvalidUniverse => write!(f, "the universe is Invalid"),
+            Anomaly::UnknownUniverse(universe) => write!(f, "unknown universe {}", universe),
+            Anomaly::InvalidType => write!(f, "the account type is Invalid"),
+            Anomaly::UnknownType(type_) => write!(f, "unknown account type {}", type_),
+            Anomaly::UnexpectedInstance(instance) => {
+                write!(f, "instance {} doesn't match the account type", instance)
+            }
+            Anomaly::ZeroAccountId => write!(f, "the account id is zero"),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::SteamID;
+
+/// The text formats a SteamID can be written in.
+///
+/// Returned by [`SteamID::parse_with_format`] and used by [`SteamID::render`].
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum SteamIdFormat {
+    /// A SteamID64 such as `76561198403256399`.
+    Steam64,
+    /// A Steam2 ID such as `STEAM_0:1:221495335`.
+    ///
+    /// `public_as_zero` is true when the public universe is written as `STEAM_0`,
+    /// like older engines do, and false for `STEAM_1`.
+    ///
+    /// `account_width` is the number of digits the last number is padded to with leading
+    /// zeros, as in `STEAM_0:1:0221495335`. 0 writes it without padding.
+    Steam2 {
+        public_as_zero: bool,
+        account_width: usize,
+    },
+    /// A Steam3 ID such as `[U:1:442990671]`.
+    ///
+    /// `instance` is true when the instance is always written out, as in `[U:1:442990671:1]`.
+    Steam3 { instance: bool },
+    /// A Steam Community profile URL such as
+    /// `https://steamcommunity.com/profiles/76561198403256399`.
+    ProfileUrl,
+    /// A Steam Community group URL such as `https://steamcommunity.com/gid/103582791429521412`.
+    GroupUrl,
+    /// A friend invite link such as `https://s.team/p/cfbf-hwkc`.
+    InviteUrl,
+}
+
+impl SteamID {
+    /// Renders the SteamID in the given format.
+    /// Returns None if the SteamID can't be written in that format,
+    /// such as a clan in Steam2.
+    ///
+    /// With the format [`SteamID::parse_with_format`] returned, this writes the input back
+    /// exactly, except for the few things a format doesn't record:
+    ///
+    /// - Leading zeros are only kept in the account number of a Steam2 ID.
+    /// - A chat flag written in the instance of a `[T:...]` ID comes back as its own
+    ///   letter, `L` or `c`.
+    /// - URLs come back in their canonical form, with `https://` and without `www.`
+    ///   or anything after the ID.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{SteamID, SteamIdFormat};
+    ///
+    /// let inputs = [
+    ///     "76561198403256399",
+    ///     "STEAM_0:1:221495335",
+    ///     "STEAM_1:1:221495335",
+    ///     "STEAM_0:1:0221495335",
+    ///     "[U:1:442990671]",
+    ///     "[U:1:442990671:1]",
+    ///     "[A:1:123]",
+    ///     "[A:1:3751:7624]",
+    ///     "[M:1:5]",
+    ///     "https://steamcommunity.com/profiles/76561198403256399",
+    ///     "https://steamcommunity.com/gid/103582791429521412",
+    ///     "https://s.team/p/cpjk-mbgw",
+    /// ];
+    ///
+    /// for input in inputs {
+    ///     let (steamid, format) = SteamID::parse_with_format(input).unwrap();
+    ///     assert_eq!(steamid.render(format).unwrap(), input);
+    /// }
+    ///
+    /// let exceptions = [
+    ///     ("076561198403256399", "76561198403256399"),
+    ///     ("[U:1:0442990671]", "[U:1:442990671]"),
+    ///     ("STEAM_0:01:1", "STEAM_0:1:1"),
+    ///     ("[T:1:5:262144]", "[L:1:5:0]"),
+    ///     ("steamcommunity.com/profiles/76561198403256399/", "https://steamcommunity.com/profiles/76561198403256399"),
+    /// ];
+    ///
+    /// for (input, rendered) in exceptions {
+    ///     let (steamid, format) = SteamID::parse_with_format(input).unwrap();
+    ///     assert_eq!(steamid.render(format).unwrap(), rendered);
+    ///     assert_eq!(SteamID::new(rendered), Some(steamid));
+    /// }
+    ///
+    /// let clan = SteamID::new("[g:1:4]").unwrap();
+    /// let format = SteamIdFormat::Steam2 { public_as_zero: true, account_width: 0 };
+    /// assert_eq!(clan.render(format), None);
+    /// ```
+    pub fn render(&self, format: SteamIdFormat) -> Option<String> {
+        match format {
+            SteamIdFormat::Steam64 => Some(self.steam64().to_string()),
+            SteamIdFormat::Steam2 {
+                public_as_zero,
+                account_width,
+            } => self.render_steam2(public_as_zero, account_width),
+            SteamIdFormat::Steam3 { instance } => Some(self.render_steam3(instance)),
+            SteamIdFormat::ProfileUrl => self.profile_url(),
+            SteamIdFormat::GroupUrl => self.group_url(),
+            SteamIdFormat::InviteUrl => self.invite_url(),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::{fmt, str::FromStr};
+
+use crate::{pad, ParseError, SteamID, SteamIdFormat};
+
+/// Who a player on a game server is.
+///
+/// Where GoldSrc and Source engines would write a player's SteamID, they write a
+/// placeholder for bots, LAN players, players still being authenticated and the server
+/// console. The placeholders differ between Steam2 and Steam3 output:
+///
+/// | Identity  | Steam2             | Steam3             |
+/// |-----------|--------------------|--------------------|
+/// | `Bot`     | `BOT`              | `BOT`              |
+/// | `Lan`     | `STEAM_ID_LAN`     | `[U:0:0]`          |
+/// | `Pending` | `STEAM_ID_PENDING` | `STEAM_ID_PENDING` |
+/// | `Unknown` | `UNKNOWN`          | `[I:0:0]`          |
+/// | `Console` | `Console`          | `Console`          |
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{PlayerIdentity, SteamIdFormat};
+///
+/// let identity: PlayerIdentity = "STEAM_ID_LAN".parse().unwrap();
+/// assert_eq!(identity, PlayerIdentity::Lan);
+/// assert_eq!(identity.render(SteamIdFormat::Steam3 { instance: false }).unwrap(), "[U:0:0]");
+///
+/// let identity: PlayerIdentity = "[U:1:442990671]".parse().unwrap();
+/// assert_eq!(format!("{:.2}", identity), "STEAM_0:1:221495335");
+/// assert_eq!(format!("{:>8}|{:<8}|", PlayerIdentity::Bot, identity), "     BOT|76561198403256399|");
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum PlayerIdentity {
+    /// A player with a SteamID.
+    Steam(SteamID),
+    /// A bot.
+    Bot,
+    /// A player on a LAN server, who isn't logged in to Steam.
+    Lan,
+    /// A player that hasn't been authenticated with Steam yet.
+    Pending,
+    /// A player the server couldn't identify.
+    Unknown,
+    /// The server console.
+    Console,
+}
+
+/// The placeholders, with their Steam2 and Steam3 spellings.
+const PLACEHOLDERS: [(PlayerIdentity, &str, &str); 5] = [
+    (PlayerIdentity::Bot, "BOT", "BOT"),
+    (PlayerIdentity::Lan, "STEAM_ID_LAN", "[U:0:0]"),
+    (
+        PlayerIdentity::Pending,
+        "STEAM_ID_PENDING",
+        "STEAM_ID_PENDING",
+    ),
+    (PlayerIdentity::Unknown, "UNKNOWN", "[I:0:0]"),
+    (PlayerIdentity::Console, "Console", "Console"),
+];
+
+impl PlayerIdentity {
+    /// Parses a SteamID in any format [`SteamID::parse`] accepts, or a placeholder
+    /// in either the Steam2 or Steam3 spelling.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{ParseErrorKind, PlayerIdentity};
+    ///
+    /// assert_eq!(PlayerIdentity::parse("[I:0:0]").unwrap(), PlayerIdentity::Unknown);
+    /// assert_eq!(PlayerIdentity::parse("BOT").unwrap(), PlayerIdentity::Bot);
+    /// assert!(PlayerIdentity::parse("STEAM_1:1:221495335").unwrap().steamid().is_some());
+    ///
+    /// let error = PlayerIdentity::parse("STEAM_ID_BOGUS").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::WrongComponentCount);
+    /// ```
+    pub fn parse(input: &str) -> Result<PlayerIdentity, ParseError> {
+        let placeholder = PLACEHOLDERS
+            .iter()
+            .find(|(_, steam2, steam3)| input == *steam2 || input == *steam3);
+
+        match placeholder {
+            Some((identity, _, _)) => Ok(*identity),
+            None => SteamID::parse(input).map(PlayerIdentity::Steam),
+        }
+    }
+
+    /// Renders the identity in the given format. Placeholders use their Steam3 spelling
+    /// for [`SteamIdFormat::Steam3`] and their Steam2 spelling for every other format.
+    ///
+    /// Returns None if the SteamID can't be written in the format.
+    pub fn render(&self, format: SteamIdFormat) -> Option<String> {
+        let PlayerIdentity::Steam(steamid) = self else {
+            return Some(String::from(self.placeholder(format)));
+        };
+
+        steamid.render(format)
+    }
+
+    /// The SteamID, if the player has one.
+    pub fn steamid(&self) -> Option<SteamID> {
+        match self {
+            PlayerIdentity::Steam(steamid) => Some(*steamid),
+            _ => None,
+        }
+    }
+
+    fn placeholder(&self, format: SteamIdFormat) -> &'static str {
+        let (_, steam2, steam3) = PLACEHOLDERS
+            .iter()
+            .find(|(identity, _, _)| identity == self)
+            .expect("every identity but Steam has a placeholder");
+
+        match format {
+            SteamIdFormat::Steam3 { .. } => steam3,
+            _ => steam2,
+        }
+    }
+}
+
+impl From<SteamID> for PlayerIdentity {
+    fn from(steamid: SteamID) -> Self {
+        PlayerIdentity::Steam(steamid)
+    }
+}
+
+impl FromStr for PlayerIdentity {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl fmt::Display for PlayerIdentity {
+    /// Formats SteamIDs the same way [`SteamID`] does. Placeholders use their Steam3
+    /// spelling with `{:#}` or `{:.3}`, and their Steam2 spelling otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = match f.precision() {
+            Some(3) => SteamIdFormat::Steam3 { instance: false },
+            None if f.alternate() => SteamIdFormat::Steam3 { instance: false },
+            _ => SteamIdFormat::Steam2 {
+                public_as_zero: true,
+                account_width: 0,
+            },
+        };
+
+        match self {
+            PlayerIdentity::Steam(steamid) => fmt::Display::fmt(steamid, f),
+            _ => pad(f, self.placeholder(format)),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    community::{strip_scheme, PATH_END},
+    strip_prefix, take_until, ParseError, ParseErrorKind, ParseOptions, SteamID, Type, Universe,
+};
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+const INVITE_ALPHABET: &[u8; 16] = b"bcdfghjkmnpqrtvw";
+
+const INVITE_URL: &str = "https://s.team/p/";
+
+impl SteamID {
+    /// The friend invite code of an individual account in the public universe,
+    /// as shown on Steam's "Add a friend" page. Returns None for other SteamIDs.
+    ///
+    /// The code is the account id in hex, written with a substituted alphabet
+    /// and split in two by a dash, like node-steamid does. The dash goes before the
+    /// middle letter, so a one letter code starts with it.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let steamid = scream_id::SteamID::new("76561198279253873").unwrap();
+    /// assert_eq!(steamid.to_invite_code().unwrap(), "cfbf-hwkc");
+    ///
+    /// let steamid = scream_id::SteamID::new("[U:1:1]").unwrap();
+    /// assert_eq!(steamid.to_invite_code().unwrap(), "-c");
+    /// assert_eq!(scream_id::SteamID::from_invite_code("-c"), Ok(steamid));
+    /// ```
+    pub fn to_invite_code(&self) -> Option<String> {
+        if self.account_type() != Type::Individual || self.universe() != Universe::Public {
+            return None;
+        }
+
+        let hex = format!("{:x}", self.account_id());
+        let mut code = String::with_capacity(hex.len() + 1);
+
+        for (i, digit) in hex.bytes().enumerate() {
+            if i == hex.len() / 2 {
+                code.push('-');
+            }
+
+            let index = HEX.iter().position(|&hex| hex == digit).unwrap();
+            code.push(INVITE_ALPHABET[index] as char);
+        }
+
+        Some(code)
+    }
+
+    /// The `https://s.team/p/` friend invite URL of an individual account in the public
+    /// universe. Returns None for other SteamIDs.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let steamid = scream_id::SteamID::new("76561198279253873").unwrap();
+    ///
+    /// assert_eq!(steamid.invite_url().unwrap(), "https://s.team/p/cfbf-hwkc");
+    /// ```
+    pub fn invite_url(&self) -> Option<String> {
+        self.to_invite_code()
+            .map(|code| format!("{}{}", INVITE_URL, code))
+    }
+
+    /// Decodes a friend invite code, or an `s.team/p/` URL containing one,
+    /// into the SteamID of an individual account in the public universe.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{ParseErrorKind, SteamID};
+    ///
+    /// let steamid = SteamID::from_invite_code("cfbf-hwkc").unwrap();
+    /// assert_eq!(steamid.steam64(), 76561198279253873);
+    ///
+    /// let steamid = SteamID::from_invite_code("https://s.team/p/cfbf-hwkc").unwrap();
+    /// assert_eq!(steamid.steam64(), 76561198279253873);
+    ///
+    /// let steamid = SteamID::from_invite_code("https://s.team/p/cfbf-hwkc#x").unwrap();
+    /// assert_eq!(SteamID::parse("https://s.team/p/cfbf-hwkc#x"), Ok(steamid));
+    ///
+    /// let error = SteamID::from_invite_code("cfbf-hwka").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidCharacter);
+    /// assert_eq!(error.span(), 8..9);
+    /// ```
+    pub fn from_invite_code(code: &str) -> Result<Self, ParseError> {
+        let options = ParseOptions::new();
+
+        let (start, code) = match strip_invite_url(code.as_bytes(), &options) {
+            Some(rest) => (code.len() - rest.len(), rest),
+            None => (0, code.as_bytes()),
+        };
+
+        // Invite links can carry a token after the code.
+        let code = take_until(code, PATH_END);
+
+        decode_invite_code(code, &options).map_err(|error| error.offset(start))
+    }
+}
+
+/// Strips `https://s.team/p/` and its variations from the start of an invite URL.
+pub(crate) const fn strip_invite_url<'a>(
+    input: &'a [u8],
+    options: &ParseOptions,
+) -> Option<&'a [u8]> {
+    strip_prefix(strip_scheme(input, options), "s.team/p/", options)
+}
+
+/// Decodes the letters of an invite code, ignoring case if the options say so.
+pub(crate) const fn decode_invite_code(
+    code: &[u8],
+    options: &ParseOptions,
+) -> Result<SteamID, ParseError> {
+    let mut account_id: u64 = 0;
+    let mut digits = 0;
+    let mut i = 0;
+
+    while i < code.len() {
+        let letter = code[i];
+        i += 1;
+
+        if letter == b'-' {
+            continue;
+        }
+
+        let letter = match options.case_insensitive {
+            true => letter.to_ascii_lowercase(),
+            false => letter,
+        };
+
+        let Some(value) = invite_digit(letter) else {
+            // The first byte of a UTF-8 char tells how long it is.
+            let len = match letter {
+                0x00..=0x7F => 1,
+                0xC0..=0xDF => 2,
+                0xE0..=0xEF => 3,
+                _ => 4,
+            };
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidCharacter,
+                i - 1..i - 1 + len,
+            ));
+        };
+
+        account_id = account_id << 4 | value as u64;
+        digits += 1;
+
+        if account_id > u32::MAX as u64 {
+            return Err(ParseError::new(
+                ParseErrorKind::AccountIdOutOfRange,
+                0..code.len(),
+            ));
+        }
+    }
+
+    if digits == 0 {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0..code.len()));
+    }
+
+    if account_id == 0 {
+        return Err(ParseError::new(
+            ParseErrorKind::ZeroAccountId,
+            0..code.len(),
+        ));
+    }
+
+    Ok(SteamID::from_individual_account_id(account_id as u32))
+}
+
+/// The value of a letter in the invite code alphabet.
+const fn invite_digit(letter: u8) -> Option<usize> {
+    let mut i = 0;
+
+    while i < INVITE_ALPHABET.len() {
+        if INVITE_ALPHABET[i] == letter {
+            return Some(i);
+        }
+        i += 1;
+    }
+
+    None
+}
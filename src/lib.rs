@@ -1,139 +1,1104 @@
+/// `?` for the const parsers, which can't use it.
+macro_rules! tri {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(error) => return Err(error),
+        }
+    };
+}
+
+mod admins;
+mod community;
+mod csgo;
+mod error;
+mod explain;
+mod format;
+mod identity;
+mod invite;
+mod literal;
+mod log;
+mod md5;
+mod options;
+mod scan;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod status;
+mod typed;
+
+use std::{
+    fmt::{self, Write},
+    ops::BitOr,
+    str::FromStr,
+};
+
+use community::ParsedUrl;
+
+pub use admins::{Admin, AdminIdentity, AdminsFile};
+pub use community::CommunityUrl;
+pub use error::{
+    AdminsError, AdminsErrorKind, ParseError, ParseErrorKind, TypedParseError, ValidationError,
+    WrongTypeError,
+};
+pub use explain::{Anomaly, Explanation};
+pub use format::SteamIdFormat;
+pub use identity::PlayerIdentity;
+pub use log::{LogEvent, LogPlayer};
+pub use options::ParseOptions;
+pub use scan::{scan, scan_with, Match, Scan, ScanOptions};
+pub use status::{parse_status, StatusPlayer};
+pub use typed::{ClanId, GameServerId, IndividualId, LobbyId};
+
 const ACCOUNT_ID_MASK: u64 = 0xFFFFFFFF;
 const ACCOUNT_INSTANCE_MASK: u64 = 0x000FFFFF;
+const CHAT_INSTANCE_FLAGS_MASK: u32 = 0xE0000;
+const ACCOUNT_TYPE_MASK: u64 = 0xF;
+const UNIVERSE_MASK: u64 = 0xFF;
+
+const ACCOUNT_INSTANCE_SHIFT: u32 = 32;
+const ACCOUNT_TYPE_SHIFT: u32 = 52;
+const UNIVERSE_SHIFT: u32 = 56;
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
 pub enum Universe {
-    Invalid = 0,
-    Public = 1,
-    Beta = 2,
-    Internal = 3,
-    Dev = 4,
+    Invalid,
+    Public,
+    Beta,
+    Internal,
+    Dev,
+    /// A universe this crate doesn't know about.
+    Other(u32),
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
 pub enum Type {
-    Invalid = 0,
-    Individual = 1,
-    Multiseat = 2,
-    GameServer = 3,
-    AnonGameServer = 4,
-    Pending = 5,
-    ContentServer = 6,
-    Clan = 7,
-    Chat = 8,
-    P2PSuperSeeder = 9,
-    AnonUser = 10,
-}
-
-#[derive(PartialEq, Debug)]
+    Invalid,
+    Individual,
+    Multiseat,
+    GameServer,
+    AnonGameServer,
+    Pending,
+    ContentServer,
+    Clan,
+    Chat,
+    P2PSuperSeeder,
+    AnonUser,
+    /// An account type this crate doesn't know about.
+    Other(u32),
+}
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
 pub enum Instance {
-    All = 0,
-    Desktop = 1,
-    Console = 2,
-    Web = 4,
+    All,
+    Desktop,
+    Console,
+    Web,
+    /// Any other instance, such as the data packed into anonymous game server IDs.
+    Other(u32),
 }
 
-#[derive(PartialEq, Debug)]
+/// Flags stored in the top bits of a chat ID's instance, telling what kind of chat it is.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::ChatFlags;
+///
+/// let flags = ChatFlags::LOBBY | ChatFlags::MMS_LOBBY;
+/// assert!(flags.contains(ChatFlags::LOBBY));
+/// assert!(!flags.contains(ChatFlags::CLAN));
+/// assert_eq!(flags.bits(), 0x60000);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct ChatFlags(u32);
+
+/// A SteamID, stored as its raw 64-bit value so no bits are ever lost.
+///
+/// The universe, type and instance are interpreted from those bits when asked for.
+/// SteamIDs are ordered by their SteamID64.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::SteamID;
+///
+/// let steamid: SteamID = "[U:1:442990671]".parse().unwrap();
+///
+/// // `{}` renders the SteamID64 and `{:#}` the Steam3 ID.
+/// assert_eq!(format!("{}", steamid), "76561198403256399");
+/// assert_eq!(format!("{:#}", steamid), "[U:1:442990671]");
+///
+/// // The precision picks a format: `.2` for Steam2, `.3` for Steam3 and `.64` for SteamID64.
+/// assert_eq!(format!("{:.2}", steamid), "STEAM_0:1:221495335");
+/// assert_eq!(format!("{:.3}", steamid), "[U:1:442990671]");
+/// assert_eq!(format!("{:.64}", steamid), "76561198403256399");
+///
+/// // SteamIDs are Copy, so they can be reused after being rendered or put in a set.
+/// let set = std::collections::BTreeSet::from([steamid, "76561197960287930".parse().unwrap()]);
+/// assert_eq!(set.first().unwrap().account_id(), 22202);
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
 pub struct SteamID {
-    universe: Universe,
-    type_: Type,
-    instance: Instance,
-    account_id: u32,
+    steam64: u64,
 }
 
 impl Universe {
-    fn from_u32(value: u32) -> Option<Universe> {
+    /// Interprets a raw universe value.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::Universe;
+    ///
+    /// assert_eq!(Universe::from_u32(1), Universe::Public);
+    /// assert_eq!(Universe::from_u32(9), Universe::Other(9));
+    /// ```
+    pub const fn from_u32(value: u32) -> Universe {
         match value {
-            0 => Some(Universe::Invalid),
-            1 => Some(Universe::Public),
-            2 => Some(Universe::Beta),
-            3 => Some(Universe::Internal),
-            4 => Some(Universe::Dev),
-            _ => None,
+            0 => Universe::Invalid,
+            1 => Universe::Public,
+            2 => Universe::Beta,
+            3 => Universe::Internal,
+            4 => Universe::Dev,
+            _ => Universe::Other(value),
+        }
+    }
+
+    /// The raw value of the universe.
+    pub const fn as_u32(&self) -> u32 {
+        match self {
+            Universe::Invalid => 0,
+            Universe::Public => 1,
+            Universe::Beta => 2,
+            Universe::Internal => 3,
+            Universe::Dev => 4,
+            Universe::Other(value) => *value,
         }
     }
 }
 
 impl Type {
-    fn from_u32(value: u32) -> Option<Type> {
+    /// Interprets a raw account type value.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::Type;
+    ///
+    /// assert_eq!(Type::from_u32(7), Type::Clan);
+    /// assert_eq!(Type::from_u32(12), Type::Other(12));
+    /// ```
+    pub const fn from_u32(value: u32) -> Type {
         match value {
-            0 => Some(Type::Invalid),
-            1 => Some(Type::Individual),
-            2 => Some(Type::Multiseat),
-            3 => Some(Type::GameServer),
-            4 => Some(Type::AnonGameServer),
-            5 => Some(Type::Pending),
-            6 => Some(Type::ContentServer),
-            7 => Some(Type::Clan),
-            8 => Some(Type::Chat),
-            9 => Some(Type::P2PSuperSeeder),
-            10 => Some(Type::AnonUser),
+            0 => Type::Invalid,
+            1 => Type::Individual,
+            2 => Type::Multiseat,
+            3 => Type::GameServer,
+            4 => Type::AnonGameServer,
+            5 => Type::Pending,
+            6 => Type::ContentServer,
+            7 => Type::Clan,
+            8 => Type::Chat,
+            9 => Type::P2PSuperSeeder,
+            10 => Type::AnonUser,
+            _ => Type::Other(value),
+        }
+    }
+
+    /// The raw value of the account type.
+    pub const fn as_u32(&self) -> u32 {
+        match self {
+            Type::Invalid => 0,
+            Type::Individual => 1,
+            Type::Multiseat => 2,
+            Type::GameServer => 3,
+            Type::AnonGameServer => 4,
+            Type::Pending => 5,
+            Type::ContentServer => 6,
+            Type::Clan => 7,
+            Type::Chat => 8,
+            Type::P2PSuperSeeder => 9,
+            Type::AnonUser => 10,
+            Type::Other(value) => *value,
+        }
+    }
+
+    /// The letter used for this type in a Steam3 ID, e.g. `U` in `[U:1:123]`.
+    const fn to_char(self) -> char {
+        match self {
+            Type::Invalid => 'I',
+            Type::Individual => 'U',
+            Type::Multiseat => 'M',
+            Type::GameServer => 'G',
+            Type::AnonGameServer => 'A',
+            Type::Pending => 'P',
+            Type::ContentServer => 'C',
+            Type::Clan => 'g',
+            Type::Chat => 'T',
+            Type::AnonUser => 'a',
+            // Not accepted by `from_char`, since it can't say which type it was.
+            Type::P2PSuperSeeder | Type::Other(_) => 'i',
+        }
+    }
+
+    /// Maps a Steam3 type letter back to its type.
+    /// `L` (lobby) and `c` (clan chat) are both chat IDs.
+    const fn from_char(value: char) -> Option<Type> {
+        match value {
+            'I' => Some(Type::Invalid),
+            'U' => Some(Type::Individual),
+            'M' => Some(Type::Multiseat),
+            'G' => Some(Type::GameServer),
+            'A' => Some(Type::AnonGameServer),
+            'P' => Some(Type::Pending),
+            'C' => Some(Type::ContentServer),
+            'g' => Some(Type::Clan),
+            'T' | 'L' | 'c' => Some(Type::Chat),
+            'a' => Some(Type::AnonUser),
             _ => None,
         }
     }
 }
 
 impl Instance {
-    fn from_u32(value: u32) -> Option<Instance> {
+    /// Interprets a raw instance value.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::Instance;
+    ///
+    /// assert_eq!(Instance::from_u32(1), Instance::Desktop);
+    /// assert_eq!(Instance::from_u32(3), Instance::Other(3));
+    /// ```
+    pub const fn from_u32(value: u32) -> Instance {
         match value {
-            0 => Some(Instance::All),
-            1 => Some(Instance::Desktop),
-            2 => Some(Instance::Console),
-            4 => Some(Instance::Web),
-            _ => None,
+            0 => Instance::All,
+            1 => Instance::Desktop,
+            2 => Instance::Console,
+            4 => Instance::Web,
+            _ => Instance::Other(value),
         }
     }
+
+    /// The raw value of the instance.
+    pub const fn as_u32(&self) -> u32 {
+        match self {
+            Instance::All => 0,
+            Instance::Desktop => 1,
+            Instance::Console => 2,
+            Instance::Web => 4,
+            Instance::Other(value) => *value,
+        }
+    }
+}
+
+impl ChatFlags {
+    /// The chat room of a clan (Steam group).
+    pub const CLAN: ChatFlags = ChatFlags(0x80000);
+    /// A lobby.
+    pub const LOBBY: ChatFlags = ChatFlags(0x40000);
+    /// A matchmaking lobby.
+    pub const MMS_LOBBY: ChatFlags = ChatFlags(0x20000);
+
+    /// No flags set.
+    pub const fn empty() -> ChatFlags {
+        ChatFlags(0)
+    }
+
+    /// Keeps only the chat flag bits of a raw instance value.
+    pub const fn from_instance(instance: u32) -> ChatFlags {
+        ChatFlags(instance & CHAT_INSTANCE_FLAGS_MASK)
+    }
+
+    /// The raw bits of the flags, as they're stored in the instance.
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns true if no flags are set.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if every flag in `other` is set.
+    pub const fn contains(&self, other: ChatFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for ChatFlags {
+    type Output = ChatFlags;
+
+    fn bitor(self, rhs: ChatFlags) -> ChatFlags {
+        ChatFlags(self.0 | rhs.0)
+    }
 }
 
 impl SteamID {
     /// Attempt to parse a SteamID from a string.
     /// Returns None if the input is not a valid SteamID.
-    /// You can pass it any kind of SteamID. (EXCEPT Steam3 IDS TODO!)
+    /// You can pass it any kind of SteamID.
     ///
     /// # Examples:
     ///
     /// ```
     /// let steamid = scream_id::SteamID::new("23");
     /// assert_eq!(steamid, None);
+    ///
+    /// let steamid = scream_id::SteamID::new("[U:1:221495335]");
+    /// assert_eq!(steamid, scream_id::SteamID::new("76561198181761063"));
     /// ```
     pub fn new(input: &str) -> Option<Self> {
-        let mut id = Self {
-            universe: Universe::Invalid,
-            type_: Type::Invalid,
-            instance: Instance::All,
-            account_id: 0,
-        };
-
-        if let Some(id64) = SteamID::validate_steam64(input) {
-            id.universe = Universe::from_u32((id64 >> 56) as u32).unwrap_or(Universe::Invalid);
-            id.type_ = Type::from_u32(((id64 >> 52) & 0xF) as u32).unwrap_or(Type::Invalid);
-            id.instance = Instance::from_u32(((id64 >> 32) & ACCOUNT_INSTANCE_MASK) as u32)
-                .unwrap_or(Instance::All);
-            id.account_id = (id64 & ACCOUNT_ID_MASK) as u32;
-        } else if let Some(id2) = SteamID::validate_steam2(input) {
-            let mut parts = id2.split(':');
-
-            parts.next();
-            let universe = parts.next().unwrap();
-            let account_id = parts.next().unwrap();
-
-            id.type_ = Type::Individual;
-            id.instance = Instance::Desktop;
-            id.account_id = account_id.parse::<u32>().unwrap();
-            id.universe = match universe.parse::<u32>().unwrap() {
-                0 => Universe::Public, // If 0 it should be public?
+        Self::parse(input).ok()
+    }
+
+    /// Parses a SteamID from a string, explaining what was wrong if it can't.
+    /// Accepts SteamID64, Steam2 and Steam3 IDs, profile and group URLs and friend invite links.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{ParseErrorKind, SteamID};
+    ///
+    /// assert!(SteamID::parse("STEAM_0:1:221495335").is_ok());
+    ///
+    /// let error = SteamID::parse("[U:1:221495335:1048576]").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InstanceOutOfRange);
+    /// assert_eq!(error.span(), 15..22);
+    ///
+    /// let error = SteamID::parse("7656119840325639").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidLength);
+    /// ```
+    ///
+    /// SteamID64s keep every bit, even ones this crate doesn't understand, and the Steam3 ID
+    /// of any type with a letter parses back to the same SteamID:
+    ///
+    /// ```
+    /// use scream_id::{SteamID, Type};
+    ///
+    /// let steamid = SteamID::parse("90104737378078375").unwrap();
+    /// assert_eq!(steamid.account_type(), Type::AnonGameServer);
+    /// assert_eq!(steamid.render_as_steam3(), "[A:1:3751:7624]");
+    /// assert_eq!(SteamID::new(&steamid.render_as_steam3()), Some(steamid));
+    /// ```
+    ///
+    /// The exception is universe 0, which Steam doesn't use. Its SteamID64s have fewer than
+    /// 17 digits, so the text `Display` writes for them is rejected unless
+    /// [`ParseOptions::short_steam64`] is set:
+    ///
+    /// ```
+    /// use scream_id::{Instance, ParseErrorKind, ParseOptions, SteamID, Type, Universe};
+    ///
+    /// let steamid = SteamID::from_parts(Universe::Invalid, Type::Individual, Instance::Desktop, 5);
+    /// assert_eq!(steamid.to_string(), "4503603922337797");
+    ///
+    /// let error = SteamID::parse(&steamid.to_string()).unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidLength);
+    ///
+    /// let options = ParseOptions::new().short_steam64(true);
+    /// assert_eq!(SteamID::parse_with(&steamid.to_string(), &options), Ok(steamid));
+    /// ```
+    pub const fn parse(input: &str) -> Result<Self, ParseError> {
+        match Self::parse_with_format(input) {
+            Ok((steamid, _)) => Ok(steamid),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Parses a SteamID like [`SteamID::parse`], and also returns the format it was written in.
+    ///
+    /// Pass the format to [`SteamID::render`] to write the SteamID back the same way,
+    /// with the few exceptions listed there.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{SteamID, SteamIdFormat};
+    ///
+    /// let (steamid, format) = SteamID::parse_with_format("STEAM_1:1:221495335").unwrap();
+    ///
+    /// assert_eq!(format, SteamIdFormat::Steam2 { public_as_zero: false, account_width: 0 });
+    /// assert_eq!(steamid.render(format).unwrap(), "STEAM_1:1:221495335");
+    /// ```
+    pub const fn parse_with_format(input: &str) -> Result<(Self, SteamIdFormat), ParseError> {
+        // The default options don't trim, so there's nothing to do before parsing.
+        Self::parse_untrimmed(input.as_bytes(), &ParseOptions::new())
+    }
+
+    /// Parses a SteamID with the given options, to accept messier input than
+    /// [`SteamID::parse`] does or to reject suspicious input.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{ParseOptions, SteamID};
+    ///
+    /// let options = ParseOptions::new().trim(true).allow_steam2(false);
+    ///
+    /// assert!(SteamID::parse_with(" 76561198403256399 ", &options).is_ok());
+    /// assert!(SteamID::parse_with("STEAM_0:1:221495335", &options).is_err());
+    /// ```
+    pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        Self::parse_with_options(input, options).map(|(steamid, _)| steamid)
+    }
+
+    pub(crate) fn parse_with_options(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, SteamIdFormat), ParseError> {
+        let (start, input) = match options.trim {
+            true => (input.len() - input.trim_start().len(), input.trim()),
+            false => (0, input),
+        };
+
+        Self::parse_untrimmed(input.as_bytes(), options).map_err(|error| error.offset(start))
+    }
+
+    /// Parses a SteamID that has already been trimmed. Const, so [`steamid!`] can use it.
+    const fn parse_untrimmed(
+        input: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, SteamIdFormat), ParseError> {
+        let (steamid, format) = tri!(if input.is_empty() {
+            Err(ParseError::new(ParseErrorKind::Empty, 0..0))
+        } else if starts_with(input, "STEAM_", options) {
+            Self::parse_steam2(input, options)
+        } else if input[0] == b'[' {
+            Self::parse_steam3(input, options)
+        } else if input[0].is_ascii_digit() {
+            let steamid = Self::from_steam64(tri!(Self::parse_steam64(input, options)));
+            Ok((steamid, SteamIdFormat::Steam64))
+        } else {
+            Self::parse_community_url(input, options)
+        });
+
+        let span = 0..input.len();
+
+        if !options.allows_format(format) {
+            return Err(ParseError::new(ParseErrorKind::FormatNotAllowed, span));
+        }
+
+        if !options.allows_universe(steamid.universe()) {
+            return Err(ParseError::new(ParseErrorKind::UniverseNotAllowed, span));
+        }
+
+        if !options.allows_type(steamid.account_type()) {
+            return Err(ParseError::new(ParseErrorKind::TypeNotAllowed, span));
+        }
+
+        Ok((steamid, format))
+    }
+
+    const fn from_steam64(steam64: u64) -> Self {
+        Self { steam64 }
+    }
+
+    /// Builds the SteamID of an individual account in the public universe.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// const ADMIN: SteamID = SteamID::from_individual_account_id(442990671);
+    ///
+    /// assert_eq!(ADMIN.steam64(), 76561198403256399);
+    /// ```
+    pub const fn from_individual_account_id(account_id: u32) -> Self {
+        Self::from_parts(
+            Universe::Public,
+            Type::Individual,
+            Instance::Desktop,
+            account_id,
+        )
+    }
+
+    /// Builds a SteamID out of its parts.
+    ///
+    /// Values in [`Universe::Other`], [`Type::Other`] and [`Instance::Other`] are cut down
+    /// to the bits they're stored in.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{Instance, SteamID, Type, Universe};
+    ///
+    /// const GROUP: SteamID = SteamID::from_parts(Universe::Public, Type::Clan, Instance::All, 4);
+    ///
+    /// assert_eq!(GROUP.render_as_steam3(), "[g:1:4]");
+    /// ```
+    pub const fn from_parts(
+        universe: Universe,
+        type_: Type,
+        instance: Instance,
+        account_id: u32,
+    ) -> Self {
+        Self::from_raw_parts(
+            universe.as_u32(),
+            type_.as_u32(),
+            instance.as_u32(),
+            account_id,
+        )
+    }
+
+    const fn from_raw_parts(universe: u32, type_: u32, instance: u32, account_id: u32) -> Self {
+        Self {
+            steam64: (universe as u64 & UNIVERSE_MASK) << UNIVERSE_SHIFT
+                | (type_ as u64 & ACCOUNT_TYPE_MASK) << ACCOUNT_TYPE_SHIFT
+                | (instance as u64 & ACCOUNT_INSTANCE_MASK) << ACCOUNT_INSTANCE_SHIFT
+                | account_id as u64,
+        }
+    }
+
+    /// The universe the SteamID belongs to.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{SteamID, Universe};
+    ///
+    /// let steamid = SteamID::new("76561198403256399").unwrap();
+    /// assert_eq!(steamid.universe(), Universe::Public);
+    /// ```
+    pub const fn universe(&self) -> Universe {
+        Universe::from_u32((self.steam64 >> UNIVERSE_SHIFT & UNIVERSE_MASK) as u32)
+    }
+
+    /// The type of account the SteamID refers to.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{SteamID, Type};
+    ///
+    /// let steamid = SteamID::new("[g:1:4]").unwrap();
+    /// assert_eq!(steamid.account_type(), Type::Clan);
+    /// ```
+    pub const fn account_type(&self) -> Type {
+        Type::from_u32((self.steam64 >> ACCOUNT_TYPE_SHIFT & ACCOUNT_TYPE_MASK) as u32)
+    }
+
+    /// The instance of the SteamID.
+    /// For chat IDs the chat flags are left out, see [`SteamID::chat_flags`].
+    ///
+    /// Values that aren't a known instance are kept as [`Instance::Other`]:
+    ///
+    /// ```
+    /// use scream_id::{Instance, SteamID};
+    ///
+    /// let steamid = SteamID::new("[A:1:123:456]").unwrap();
+    /// assert_eq!(steamid.instance(), Instance::Other(456));
+    ///
+    /// let steamid = SteamID::new("[L:1:123]").unwrap();
+    /// assert_eq!(steamid.instance(), Instance::All);
+    /// ```
+    pub const fn instance(&self) -> Instance {
+        let instance = self.raw_instance();
+
+        match self.account_type() {
+            Type::Chat => Instance::from_u32(instance & !CHAT_INSTANCE_FLAGS_MASK),
+            _ => Instance::from_u32(instance),
+        }
+    }
+
+    /// The chat flags of a chat ID. Always empty for other types.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{ChatFlags, SteamID};
+    ///
+    /// let steamid = SteamID::new("[c:1:4]").unwrap();
+    /// assert_eq!(steamid.chat_flags(), ChatFlags::CLAN);
+    /// ```
+    pub const fn chat_flags(&self) -> ChatFlags {
+        match self.account_type() {
+            Type::Chat => ChatFlags::from_instance(self.raw_instance()),
+            _ => ChatFlags::empty(),
+        }
+    }
+
+    /// Returns true if the SteamID is a lobby, including matchmaking lobbies.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let steamid = scream_id::SteamID::new("109212290963734533").unwrap();
+    /// assert_eq!(steamid.render_as_steam3(), "[L:1:5]");
+    /// assert!(steamid.is_lobby());
+    /// ```
+    pub const fn is_lobby(&self) -> bool {
+        let flags = self.chat_flags();
+        flags.contains(ChatFlags::LOBBY) || flags.contains(ChatFlags::MMS_LOBBY)
+    }
+
+    /// Returns true if the SteamID is a chat room of any kind, including lobbies and clan chats.
+    /// The same as node-steamid's `isSteamChat`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// assert!(SteamID::new("[T:1:5]").unwrap().is_steam_chat());
+    /// assert!(SteamID::new("[L:1:5]").unwrap().is_steam_chat());
+    /// assert!(!SteamID::new("[g:1:5]").unwrap().is_steam_chat());
+    /// ```
+    pub const fn is_steam_chat(&self) -> bool {
+        matches!(self.account_type(), Type::Chat)
+    }
+
+    /// Returns true if the SteamID is the chat room of a group, which node-steamid's
+    /// `isGroupChat` means as a clan chat. The same as [`SteamID::is_clan_chat`].
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// assert!(SteamID::new("[c:1:5]").unwrap().is_group_chat());
+    /// assert!(!SteamID::new("[T:1:5]").unwrap().is_group_chat());
+    /// assert!(!SteamID::new("[L:1:5]").unwrap().is_group_chat());
+    /// ```
+    pub const fn is_group_chat(&self) -> bool {
+        self.is_clan_chat()
+    }
+
+    /// Returns true if the SteamID is the chat room of a clan (Steam group).
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// let steamid = SteamID::new("[c:1:5]").unwrap();
+    /// assert!(steamid.is_clan_chat());
+    /// assert!(steamid.is_steam_chat());
+    ///
+    /// let steamid = SteamID::new("[T:1:5]").unwrap();
+    /// assert!(!steamid.is_clan_chat());
+    /// ```
+    pub const fn is_clan_chat(&self) -> bool {
+        self.chat_flags().contains(ChatFlags::CLAN)
+    }
+
+    /// Returns true if the SteamID passes the same checks as node-steamid's `isValid`.
+    /// See [`SteamID::validate`] for the rules.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// assert!(SteamID::new("[U:1:442990671]").unwrap().is_valid());
+    /// assert!(!SteamID::new("[U:0:442990671]").unwrap().is_valid());
+    /// ```
+    pub const fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Returns true if the SteamID is a valid individual account in the public universe,
+    /// on the desktop instance.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// assert!(SteamID::new("STEAM_0:1:221495335").unwrap().is_valid_individual());
+    /// assert!(!SteamID::new("[U:1:442990671:4]").unwrap().is_valid_individual());
+    /// assert!(!SteamID::new("[g:1:4]").unwrap().is_valid_individual());
+    /// ```
+    pub const fn is_valid_individual(&self) -> bool {
+        matches!(self.universe(), Universe::Public)
+            && matches!(self.account_type(), Type::Individual)
+            && matches!(self.instance(), Instance::Desktop)
+            && self.is_valid()
+    }
+
+    /// Checks the SteamID against node-steamid's rules, and returns the first one it breaks:
+    ///
+    /// - The universe is between [`Universe::Public`] and [`Universe::Dev`].
+    /// - The type is between [`Type::Individual`] and [`Type::AnonUser`].
+    /// - Individual accounts have an account id and an instance up to [`Instance::Web`].
+    /// - Clans have an account id and the [`Instance::All`] instance.
+    /// - Game servers have an account id.
+    ///
+    /// The parsers accept SteamIDs that break these rules, so they can be looked at.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{ParseOptions, SteamID, ValidationError};
+    ///
+    /// let clan = SteamID::new("[g:1:4:1]").unwrap();
+    /// assert_eq!(clan.validate(), Err(ValidationError::ClanInstance));
+    ///
+    /// let options = ParseOptions::new().zero_account_id(true);
+    /// let server = SteamID::parse_with("[G:1:0]", &options).unwrap();
+    /// assert_eq!(server.validate(), Err(ValidationError::ZeroAccountId));
+    ///
+    /// let lobby = SteamID::new("[L:1:5]").unwrap();
+    /// assert_eq!(lobby.validate(), Ok(()));
+    /// ```
+    pub const fn validate(&self) -> Result<(), ValidationError> {
+        let universe = self.universe().as_u32();
+        if universe <= Universe::Invalid.as_u32() || universe > Universe::Dev.as_u32() {
+            return Err(ValidationError::Universe);
+        }
+
+        let type_ = self.account_type().as_u32();
+        if type_ <= Type::Invalid.as_u32() || type_ > Type::AnonUser.as_u32() {
+            return Err(ValidationError::Type);
+        }
+
+        let has_account_id = self.account_id() != 0;
+
+        match self.account_type() {
+            Type::Individual | Type::Clan | Type::GameServer if !has_account_id => {
+                Err(ValidationError::ZeroAccountId)
+            }
+            Type::Individual if self.raw_instance() > Instance::Web.as_u32() => {
+                Err(ValidationError::IndividualInstance)
+            }
+            Type::Clan if self.raw_instance() != Instance::All.as_u32() => {
+                Err(ValidationError::ClanInstance)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Converts the SteamID of a clan into the SteamID of its group chat.
+    /// Fails if the SteamID isn't a clan.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let clan = scream_id::SteamID::new("[g:1:4]").unwrap();
+    /// let chat = clan.clan_to_chat().unwrap();
+    ///
+    /// assert_eq!(chat.render_as_steam3(), "[c:1:4]");
+    /// assert_eq!(chat.chat_to_clan().unwrap(), clan);
+    ///
+    /// let player = scream_id::SteamID::new("[U:1:4]").unwrap();
+    /// assert!(player.clan_to_chat().is_err());
+    /// ```
+    pub fn clan_to_chat(&self) -> Result<SteamID, WrongTypeError> {
+        if self.account_type() != Type::Clan {
+            return Err(WrongTypeError::new(*self, "a clan"));
+        }
+
+        Ok(Self::from_raw_parts(
+            self.universe().as_u32(),
+            Type::Chat.as_u32(),
+            ChatFlags::CLAN.bits(),
+            self.account_id(),
+        ))
+    }
+
+    /// Converts the SteamID of a clan's group chat back into the SteamID of the clan.
+    /// Fails if the SteamID isn't a clan chat.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let chat = scream_id::SteamID::new("110338190870577156").unwrap();
+    ///
+    /// assert_eq!(chat.chat_to_clan().unwrap().render_as_steam3(), "[g:1:4]");
+    /// assert!(scream_id::SteamID::new("[T:1:4]").unwrap().chat_to_clan().is_err());
+    /// ```
+    pub fn chat_to_clan(&self) -> Result<SteamID, WrongTypeError> {
+        if !self.is_clan_chat() {
+            return Err(WrongTypeError::new(*self, "a clan chat"));
+        }
+
+        Ok(Self::from_raw_parts(
+            self.universe().as_u32(),
+            Type::Clan.as_u32(),
+            Instance::All.as_u32(),
+            self.account_id(),
+        ))
+    }
+
+    const fn raw_instance(&self) -> u32 {
+        (self.steam64 >> ACCOUNT_INSTANCE_SHIFT & ACCOUNT_INSTANCE_MASK) as u32
+    }
+
+    /// The 32-bit account id of the SteamID.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let steamid = scream_id::SteamID::new("STEAM_0:1:221495335").unwrap();
+    /// assert_eq!(steamid.account_id(), 442990671);
+    /// ```
+    pub const fn account_id(&self) -> u32 {
+        (self.steam64 & ACCOUNT_ID_MASK) as u32
+    }
+
+    /// The SteamID as a SteamID64.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let steamid = scream_id::SteamID::new("STEAM_0:1:221495335").unwrap();
+    /// assert_eq!(steamid.steam64(), 76561198403256399);
+    /// ```
+    pub const fn steam64(&self) -> u64 {
+        self.steam64
+    }
+
+    pub(crate) const fn parse_steam64(
+        input: &[u8],
+        options: &ParseOptions,
+    ) -> Result<u64, ParseError> {
+        let span = 0..input.len();
+
+        if !is_digits(input) {
+            return Err(ParseError::new(ParseErrorKind::NonNumeric, span));
+        }
+
+        // Individual IDs have 17 digits, clans and chats have 18. Only universe 0 has fewer.
+        let min_len = if options.short_steam64 { 1 } else { 17 };
+
+        if input.len() < min_len || input.len() > 20 {
+            return Err(ParseError::new(ParseErrorKind::InvalidLength, span));
+        }
+
+        if !options.leading_zeros && input[0] == b'0' {
+            return Err(ParseError::new(ParseErrorKind::LeadingZero, span));
+        }
+
+        let Some(id64) = parse_digits(input) else {
+            return Err(ParseError::new(ParseErrorKind::Overflow, span));
+        };
+
+        if id64 & ACCOUNT_ID_MASK == 0 && !options.zero_account_id {
+            return Err(ParseError::new(ParseErrorKind::ZeroAccountId, span));
+        }
+
+        Ok(id64)
+    }
+
+    const fn parse_steam2(
+        input: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, SteamIdFormat), ParseError> {
+        // EG: STEAM_0:0:23071901
+
+        let (prefix, auth_server, account_number) = match split_once(input, b':') {
+            Some((prefix, rest)) => match split_once(rest, b':') {
+                Some((auth_server, account_number)) if find(account_number, b':').is_none() => {
+                    (prefix, auth_server, account_number)
+                }
                 _ => {
-                    Universe::from_u32(universe.parse::<u32>().unwrap()).unwrap_or(Universe::Public)
+                    return Err(ParseError::new(
+                        ParseErrorKind::WrongComponentCount,
+                        0..input.len(),
+                    ))
                 }
+            },
+            None => {
+                return Err(ParseError::new(
+                    ParseErrorKind::WrongComponentCount,
+                    0..input.len(),
+                ))
             }
-        } else {
-            return None;
+        };
+
+        let Some(universe) = strip_prefix(prefix, "STEAM_", options) else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnknownPrefix,
+                0..prefix.len(),
+            ));
+        };
+
+        let universe = tri!(parse_number(
+            universe,
+            "STEAM_".len(),
+            UNIVERSE_MASK,
+            ParseErrorKind::UniverseOutOfRange,
+            options,
+        ));
+        let start = prefix.len() + 1;
+        let auth_server_bit = tri!(parse_number(
+            auth_server,
+            start,
+            1,
+            ParseErrorKind::InvalidParityBit,
+            options,
+        ));
+        let start = start + auth_server.len() + 1;
+        let account_digits = account_number;
+        let account_number = tri!(parse_number(
+            account_number,
+            start,
+            (u32::MAX >> 1) as u64,
+            ParseErrorKind::AccountIdOutOfRange,
+            options,
+        ));
+
+        let account_id = (account_number * 2 + auth_server_bit) as u32;
+
+        if account_id == 0 && !options.zero_account_id {
+            return Err(ParseError::new(
+                ParseErrorKind::ZeroAccountId,
+                start..input.len(),
+            ));
         }
 
-        Some(id)
+        // Older engines render the public universe as 0.
+        let format = SteamIdFormat::Steam2 {
+            public_as_zero: universe == 0,
+            account_width: match account_digits {
+                [b'0', _, ..] => account_digits.len(),
+                _ => 0,
+            },
+        };
+        let universe = match universe {
+            0 => Universe::Public.as_u32(),
+            universe => universe as u32,
+        };
+
+        let steamid = Self::from_raw_parts(
+            universe,
+            Type::Individual.as_u32(),
+            Instance::Desktop.as_u32(),
+            account_id,
+        );
+
+        Ok((steamid, format))
+    }
+
+    pub(crate) const fn parse_steam3(
+        input: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, SteamIdFormat), ParseError> {
+        // EG: [U:1:221495335] or [A:1:123:456]
+
+        let Some((b'[', inner)) = input.split_first() else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnknownPrefix,
+                0..input.len(),
+            ));
+        };
+
+        let Some((b']', inner)) = inner.split_last() else {
+            return Err(ParseError::new(
+                ParseErrorKind::Unterminated,
+                input.len()..input.len(),
+            ));
+        };
+
+        let Some((type_letter, rest)) = split_once(inner, b':') else {
+            return Err(ParseError::new(
+                ParseErrorKind::WrongComponentCount,
+                0..input.len(),
+            ));
+        };
+        let Some((universe, rest)) = split_once(rest, b':') else {
+            return Err(ParseError::new(
+                ParseErrorKind::WrongComponentCount,
+                0..input.len(),
+            ));
+        };
+        let (account_id, instance) = match split_once(rest, b':') {
+            Some((_, instance)) if find(instance, b':').is_some() => {
+                return Err(ParseError::new(
+                    ParseErrorKind::WrongComponentCount,
+                    0..input.len(),
+                ))
+            }
+            Some((account_id, instance)) => (account_id, Some(instance)),
+            None => (rest, None),
+        };
+
+        let type_ = match type_letter {
+            [letter] => Type::from_char(*letter as char),
+            _ => None,
+        };
+        let Some(type_) = type_ else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnknownType,
+                1..1 + type_letter.len(),
+            ));
+        };
+
+        let start = 1 + type_letter.len() + 1;
+        let universe_value = tri!(parse_number(
+            universe,
+            start,
+            UNIVERSE_MASK,
+            ParseErrorKind::UniverseOutOfRange,
+            options,
+        )) as u32;
+
+        let start = start + universe.len() + 1;
+        let account_span = start..start + account_id.len();
+        let account_id_value = tri!(parse_number(
+            account_id,
+            start,
+            u32::MAX as u64,
+            ParseErrorKind::AccountIdOutOfRange,
+            options,
+        )) as u32;
+
+        if account_id_value == 0 && !options.zero_account_id {
+            return Err(ParseError::new(ParseErrorKind::ZeroAccountId, account_span));
+        }
+
+        let start = start + account_id.len() + 1;
+        let instance_value = match instance {
+            Some(instance) => tri!(parse_number(
+                instance,
+                start,
+                ACCOUNT_INSTANCE_MASK,
+                ParseErrorKind::InstanceOutOfRange,
+                options,
+            )) as u32,
+            None if matches!(type_, Type::Individual) => Instance::Desktop.as_u32(),
+            None => Instance::All.as_u32(),
+        };
+
+        let flags = match type_letter {
+            b"c" => ChatFlags::CLAN,
+            b"L" => ChatFlags::LOBBY,
+            _ => ChatFlags::empty(),
+        };
+        let instance_value = instance_value | flags.bits();
+
+        let steamid = Self::from_raw_parts(
+            universe_value,
+            type_.as_u32(),
+            instance_value,
+            account_id_value,
+        );
+        let format = SteamIdFormat::Steam3 {
+            instance: instance.is_some(),
+        };
+
+        Ok((steamid, format))
+    }
+
+    const fn parse_community_url(
+        input: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, SteamIdFormat), ParseError> {
+        match tri!(CommunityUrl::parse_untrimmed(input, options)) {
+            ParsedUrl::Profile(steamid) => Ok((steamid, SteamIdFormat::ProfileUrl)),
+            ParsedUrl::Group(steamid) => Ok((steamid, SteamIdFormat::GroupUrl)),
+            ParsedUrl::Invite(steamid) => Ok((steamid, SteamIdFormat::InviteUrl)),
+            ParsedUrl::ProfileVanity(_) | ParsedUrl::GroupVanity(_) => {
+                Err(ParseError::new(ParseErrorKind::VanityUrl, 0..input.len()))
+            }
+        }
     }
 
-    /// Tries to render the SteamID as a string.
+    /// Tries to render the SteamID as a Steam2 string.
+    /// Returns None if the SteamID isn't an individual account.
+    ///
+    /// The lowest bit of the account id becomes `Y` and the rest becomes `Z` in `STEAM_X:Y:Z`.
     ///
     /// # Examples:
     /// ```
@@ -141,30 +1106,135 @@ impl SteamID {
     ///
     /// assert_eq!(steamid.unwrap().render_as_steam2(), Some(String::from("STEAM_0:1:221495335")));
     /// ```
-    pub fn render_as_steam2<'a>(self) -> Option<String> {
-        if self.type_ != Type::Individual {
+    ///
+    /// Steam2 IDs round-trip through SteamID64:
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// let table = [
+    ///     ("STEAM_0:1:221495335", "76561198403256399"),
+    ///     ("STEAM_0:0:221495335", "76561198403256398"),
+    ///     ("STEAM_0:0:11101", "76561197960287930"),
+    ///     ("STEAM_0:1:0", "76561197960265729"),
+    ///     ("STEAM_0:1:2147483647", "76561202255233023"),
+    /// ];
+    ///
+    /// for (steam2, steam64) in table {
+    ///     assert_eq!(SteamID::new(steam2), SteamID::new(steam64));
+    ///     assert_eq!(SteamID::new(steam64).unwrap().render_as_steam2().unwrap(), steam2);
+    /// }
+    ///
+    /// // STEAM_1 is the same account in the public universe.
+    /// assert_eq!(SteamID::new("STEAM_1:1:221495335"), SteamID::new("76561198403256399"));
+    /// ```
+    pub fn render_as_steam2(&self) -> Option<String> {
+        self.render_steam2(true, 0)
+    }
+
+    fn render_steam2(&self, public_as_zero: bool, account_width: usize) -> Option<String> {
+        if self.account_type() != Type::Individual {
             return None;
         }
 
-        let mut universe = self.universe as u32;
-
-        if universe == 1 {
-            universe = 0;
-        }
+        let universe = match self.universe() {
+            Universe::Public if public_as_zero => 0,
+            universe => universe.as_u32(),
+        };
 
         Some(format!(
-            "STEAM_{}:{}:{}",
+            "STEAM_{}:{}:{:0width$}",
             universe,
-            self.instance as u32,
-            ((self.account_id / 2) as f64).floor()
+            self.account_id() & 1,
+            self.account_id() >> 1,
+            width = account_width
         ))
     }
 
-    /*
-    pub fn validate_3(input: &str) -> Option<&str> {
-        todo!()
+    /// Tries to render the SteamID as a Steam3 string.
+    ///
+    /// The instance is only included when it can't be implied from the type.
+    ///
+    /// `P2PSuperSeeder` and unknown types have no letter of their own and are written with `i`,
+    /// like node-steamid does. [`SteamID::parse`] can't tell which type that was, so it rejects
+    /// `i`, and these SteamIDs don't round-trip through Steam3. Use the SteamID64 for them.
+    ///
+    /// # Examples:
+    /// ```
+    /// let steamid = scream_id::SteamID::new("76561198181761063").unwrap();
+    /// assert_eq!(steamid.render_as_steam3(), "[U:1:221495335]");
+    ///
+    /// let steamid = scream_id::SteamID::new("[U:1:221495335:4]").unwrap();
+    /// assert_eq!(steamid.render_as_steam3(), "[U:1:221495335:4]");
+    ///
+    /// let steamid = scream_id::SteamID::new("[g:1:4]").unwrap();
+    /// assert_eq!(steamid.render_as_steam3(), "[g:1:4]");
+    ///
+    /// let steamid = scream_id::SteamID::new("[c:1:4]").unwrap();
+    /// assert_eq!(steamid.render_as_steam3(), "[c:1:4]");
+    ///
+    /// // A matchmaking lobby has no letter of its own, so the flag is kept in the instance.
+    /// let steamid = scream_id::SteamID::new("[L:1:4:131072]").unwrap();
+    /// assert_eq!(steamid.render_as_steam3(), "[L:1:4:131072]");
+    ///
+    /// let steamid = scream_id::SteamID::new("112589990684262404").unwrap();
+    /// assert_eq!(steamid.render_as_steam3(), "[i:1:4]");
+    /// assert_eq!(scream_id::SteamID::new("[i:1:4]"), None);
+    /// ```
+    pub fn render_as_steam3(&self) -> String {
+        self.render_steam3(false)
+    }
+
+    fn render_steam3(&self, force_instance: bool) -> String {
+        let type_ = self.account_type();
+        let instance = self.instance();
+        let flags = self.chat_flags();
+
+        // Clan chats and lobbies get their own letter, which implies their flag.
+        let (type_char, implied_flags) = if flags.contains(ChatFlags::CLAN) {
+            ('c', ChatFlags::CLAN)
+        } else if flags.contains(ChatFlags::LOBBY) {
+            ('L', ChatFlags::LOBBY)
+        } else {
+            (type_.to_char(), ChatFlags::empty())
+        };
+        let flags = flags.bits() & !implied_flags.bits();
+
+        let render_instance = force_instance
+            || match type_ {
+                Type::Individual => instance != Instance::Desktop,
+                _ => instance != Instance::All || flags != 0,
+            };
+
+        let mut rendered = format!(
+            "[{}:{}:{}",
+            type_char,
+            self.universe().as_u32(),
+            self.account_id()
+        );
+
+        if render_instance {
+            rendered.push_str(&format!(":{}", instance.as_u32() | flags));
+        }
+
+        rendered.push(']');
+        rendered
+    }
+
+    /// Validates a Steam3 id and returns it if it is valid.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let id = scream_id::SteamID::validate_steam3("[U:1:221495335]").unwrap();
+    ///
+    /// assert_eq!(id, "[U:1:221495335]");
+    /// assert_eq!(scream_id::SteamID::validate_steam3("[X:1:221495335]"), None);
+    /// ```
+    pub fn validate_steam3(input: &str) -> Option<&str> {
+        Self::parse_steam3(input.as_bytes(), &ParseOptions::new())
+            .ok()
+            .map(|_| input)
     }
-     */
 
     /// Validates a Steam2 id and returns it if it is valid.
     ///
@@ -174,43 +1244,269 @@ impl SteamID {
     /// let id = scream_id::SteamID::validate_steam2("STEAM_0:1:221495335").unwrap();
     ///
     /// assert_eq!(id,"STEAM_0:1:221495335");
+    /// assert_eq!(scream_id::SteamID::validate_steam2("STEAM_0:2:221495335"), None);
+    /// assert_eq!(scream_id::SteamID::validate_steam2("STEAM_0:foo:bar"), None);
     /// ```
     pub fn validate_steam2(input: &str) -> Option<&str> {
-        // EG: STEAM_0:0:23071901
+        Self::parse_steam2(input.as_bytes(), &ParseOptions::new())
+            .ok()
+            .map(|_| input)
+    }
 
-        let mut parts = input.split(':');
+    /// Validates a SteamID64 and returns it if it is valid.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// let id = scream_id::SteamID::validate_steam64("76561198403256399").unwrap();
+    ///
+    /// assert_eq!(id,76561198403256399);
+    /// ```
+    pub fn validate_steam64(input: &str) -> Option<u64> {
+        Self::parse_steam64(input.as_bytes(), &ParseOptions::new()).ok()
+    }
+}
 
-        if parts.clone().count() != 3 {
-            return None;
+impl TryFrom<u64> for SteamID {
+    type Error = ParseError;
+
+    /// Converts a SteamID64 into a SteamID.
+    /// Fails if the account id is zero, the same as parsing the number would.
+    ///
+    /// The error has an empty span since there's no text to point at.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::SteamID;
+    ///
+    /// let steamid = SteamID::try_from(76561198403256399).unwrap();
+    /// assert_eq!(u64::from(steamid), 76561198403256399);
+    ///
+    /// assert!(SteamID::try_from(76561197960265728).is_err());
+    /// ```
+    fn try_from(steam64: u64) -> Result<Self, Self::Error> {
+        if steam64 & ACCOUNT_ID_MASK == 0 {
+            return Err(ParseError::new(ParseErrorKind::ZeroAccountId, 0..0));
         }
 
-        let universe = parts.next().unwrap();
+        Ok(Self::from_steam64(steam64))
+    }
+}
 
-        if universe != "STEAM_0" && universe != "STEAM_1" {
-            return None;
-        }
+impl From<SteamID> for u64 {
+    fn from(steamid: SteamID) -> u64 {
+        steamid.steam64
+    }
+}
 
-        Some(input)
+impl FromStr for SteamID {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
     }
+}
 
-    /// Validates a SteamID64 and returns it if it is valid.
+impl fmt::Display for SteamID {
+    /// Renders the SteamID64 by default, or the Steam3 ID with `{:#}`.
     ///
-    /// # Examples:
+    /// A precision of 2, 3 or 64 selects Steam2, Steam3 or SteamID64.
+    /// SteamIDs that can't be rendered as Steam2 fall back to Steam3.
+    ///
+    /// The SteamID64 of a SteamID in universe 0 has fewer than 17 digits, and only parses
+    /// back with [`ParseOptions::short_steam64`].
+    ///
+    /// Width, fill and alignment work as they do for strings:
     ///
     /// ```
-    /// let id = scream_id::SteamID::validate_steam64("76561198403256399").unwrap();
+    /// let steamid = scream_id::SteamID::new("76561198403256399").unwrap();
     ///
-    /// assert_eq!(id,76561198403256399);
+    /// assert_eq!(format!("{:>20}", steamid), "   76561198403256399");
+    /// assert_eq!(format!("{:-<20.3}|", steamid), "[U:1:442990671]-----|");
     /// ```
-    pub fn validate_steam64(input: &str) -> Option<u64> {
-        if input.len() == 17 {
-            if let Ok(id) = u64::from_str_radix(input, 10) {
-                if (id & ACCOUNT_ID_MASK) != 0 {
-                    return Some(id);
-                }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = match f.precision() {
+            Some(2) => self
+                .render_as_steam2()
+                .unwrap_or_else(|| self.render_as_steam3()),
+            Some(3) => self.render_as_steam3(),
+            None if f.alternate() => self.render_as_steam3(),
+            _ => self.steam64.to_string(),
+        };
+
+        pad(f, &rendered)
+    }
+}
+
+/// Writes `text` padded to the formatter's width. Unlike [`fmt::Formatter::pad`],
+/// never cuts `text` short, since the precision picks the format here.
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(text.chars().count());
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+
+    for _ in 0..before {
+        f.write_char(f.fill())?;
+    }
+
+    f.write_str(text)?;
+
+    for _ in 0..after {
+        f.write_char(f.fill())?;
+    }
+
+    Ok(())
+}
+
+/// Splits `input` around the first `separator`.
+const fn split_once(input: &[u8], separator: u8) -> Option<(&[u8], &[u8])> {
+    let Some(index) = find(input, separator) else {
+        return None;
+    };
+
+    let (head, tail) = input.split_at(index);
+    Some((head, tail.split_at(1).1))
+}
+
+/// The index of the first `byte` in `input`.
+const fn find(input: &[u8], byte: u8) -> Option<usize> {
+    let mut i = 0;
+
+    while i < input.len() {
+        if input[i] == byte {
+            return Some(i);
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Cuts `input` off at the first of the bytes in `ends`.
+pub(crate) const fn take_until<'a>(input: &'a [u8], ends: &[u8]) -> &'a [u8] {
+    let mut i = 0;
+
+    while i < input.len() {
+        let mut j = 0;
+        while j < ends.len() {
+            if input[i] == ends[j] {
+                return input.split_at(i).0;
             }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    input
+}
+
+/// Checks if `input` is `expected`, ignoring ASCII case if the options say so.
+pub(crate) const fn equals(input: &[u8], expected: &str, options: &ParseOptions) -> bool {
+    let expected = expected.as_bytes();
+
+    if input.len() != expected.len() {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < input.len() {
+        let matches = match options.case_insensitive {
+            true => input[i].eq_ignore_ascii_case(&expected[i]),
+            false => input[i] == expected[i],
+        };
+
+        if !matches {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Checks if `input` starts with `prefix`, ignoring ASCII case if the options say so.
+const fn starts_with(input: &[u8], prefix: &str, options: &ParseOptions) -> bool {
+    strip_prefix(input, prefix, options).is_some()
+}
+
+pub(crate) const fn strip_prefix<'a>(
+    input: &'a [u8],
+    prefix: &str,
+    options: &ParseOptions,
+) -> Option<&'a [u8]> {
+    if input.len() < prefix.len() {
+        return None;
+    }
+
+    let (head, rest) = input.split_at(prefix.len());
+
+    match equals(head, prefix, options) {
+        true => Some(rest),
+        false => None,
+    }
+}
+
+/// Returns true if `input` is only ASCII digits.
+const fn is_digits(input: &[u8]) -> bool {
+    let mut i = 0;
+
+    while i < input.len() {
+        if !input[i].is_ascii_digit() {
+            return false;
         }
+        i += 1;
+    }
+
+    true
+}
+
+/// Reads ASCII digits as a number. Returns None if it doesn't fit in a u64.
+const fn parse_digits(digits: &[u8]) -> Option<u64> {
+    let mut value: u64 = 0;
+    let mut i = 0;
+
+    while i < digits.len() {
+        let Some(shifted) = value.checked_mul(10) else {
+            return None;
+        };
+        let Some(sum) = shifted.checked_add((digits[i] - b'0') as u64) else {
+            return None;
+        };
+
+        value = sum;
+        i += 1;
+    }
+
+    Some(value)
+}
+
+/// Parses a component made of ASCII digits that starts at byte `start`.
+/// Values above `max` are reported as `out_of_range`.
+const fn parse_number(
+    part: &[u8],
+    start: usize,
+    max: u64,
+    out_of_range: ParseErrorKind,
+    options: &ParseOptions,
+) -> Result<u64, ParseError> {
+    let span = start..start + part.len();
+
+    if part.is_empty() || !is_digits(part) {
+        return Err(ParseError::new(ParseErrorKind::NonNumeric, span));
+    }
+
+    if !options.leading_zeros && part.len() > 1 && part[0] == b'0' {
+        return Err(ParseError::new(ParseErrorKind::LeadingZero, span));
+    }
 
-        None
+    match parse_digits(part) {
+        Some(value) if value <= max => Ok(value),
+        _ => Err(ParseError::new(out_of_range, span)),
     }
 }
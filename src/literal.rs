@@ -0,0 +1,41 @@
+use crate::SteamID;
+
+/// Parses a SteamID literal at compile time.
+///
+/// Takes anything [`SteamID::parse`] does and expands to a constant [`SteamID`].
+/// A literal that doesn't parse is a compile error instead of a `None` at runtime.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{steamid, SteamID};
+///
+/// const ADMIN: SteamID = steamid!("[U:1:442990671]");
+/// const BOT: SteamID = steamid!("STEAM_0:1:221495335");
+///
+/// assert_eq!(ADMIN.steam64(), 76561198403256399);
+/// assert_eq!(BOT, steamid!("https://steamcommunity.com/profiles/76561198403256399"));
+/// ```
+///
+/// A typo doesn't compile:
+///
+/// ```compile_fail
+/// const ADMIN: scream_id::SteamID = scream_id::steamid!("STEAM_0:2:221495335");
+/// ```
+#[macro_export]
+macro_rules! steamid {
+    ($input:literal) => {{
+        const STEAMID: $crate::SteamID = $crate::SteamID::__from_literal($input);
+        STEAMID
+    }};
+}
+
+impl SteamID {
+    #[doc(hidden)]
+    pub const fn __from_literal(input: &str) -> SteamID {
+        match SteamID::parse(input) {
+            Ok(steamid) => steamid,
+            Err(error) => panic!("{}", error.kind().message()),
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::PlayerIdentity;
+
+/// A player as written in a Source engine log, like `"Robin<12><[U:1:442990671]><CT>"`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct LogPlayer {
+    name: String,
+    userid: i32,
+    identity: PlayerIdentity,
+    team: String,
+}
+
+/// An event from a line of a Source engine server log, from srcds or CS2.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{LogEvent, PlayerIdentity, SteamID};
+///
+/// let line = r#"L 10/15/2026 - 20:14:03: "Robin<12><[U:1:442990671]><CT>" [-400 1200 64] killed "Bot Ted<3><BOT><TERRORIST>" [-380 1300 64] with "ak47" (headshot)"#;
+///
+/// let Some(LogEvent::Killed { killer, victim, weapon, headshot }) = LogEvent::parse(line) else {
+///     panic!("not a kill");
+/// };
+///
+/// assert_eq!(killer.name(), "Robin");
+/// assert_eq!(killer.userid(), 12);
+/// assert_eq!(killer.identity(), PlayerIdentity::Steam(SteamID::new("[U:1:442990671]").unwrap()));
+/// assert_eq!(killer.team(), "CT");
+/// assert_eq!(victim.identity(), PlayerIdentity::Bot);
+/// assert_eq!(weapon, "ak47");
+/// assert!(headshot);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+#[non_exhaustive]
+pub enum LogEvent {
+    /// A player connected, from the address if it was logged.
+    Connected {
+        player: LogPlayer,
+        address: Option<String>,
+    },
+    /// A player entered the game.
+    Entered { player: LogPlayer },
+    /// A player killed another.
+    Killed {
+        killer: LogPlayer,
+        victim: LogPlayer,
+        weapon: String,
+        headshot: bool,
+    },
+    /// A player wrote in chat. `team` is true for team chat.
+    Say {
+        player: LogPlayer,
+        message: String,
+        team: bool,
+    },
+    /// A player disconnected, for the reason if it was logged.
+    Disconnected {
+        player: LogPlayer,
+        reason: Option<String>,
+    },
+}
+
+impl LogPlayer {
+    /// Parses a player without the surrounding quotes, like `Robin<12><[U:1:442990671]><CT>`.
+    ///
+    /// The name can contain anything, including `<`, `>` and quotes.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{LogPlayer, PlayerIdentity};
+    ///
+    /// let player = LogPlayer::parse("<<Robin>><2><STEAM_ID_LAN><>").unwrap();
+    ///
+    /// assert_eq!(player.name(), "<<Robin>>");
+    /// assert_eq!(player.identity(), PlayerIdentity::Lan);
+    /// assert_eq!(player.team(), "");
+    /// ```
+    pub fn parse(input: &str) -> Option<LogPlayer> {
+        let (rest, team) = split_group(input)?;
+        let (rest, identity) = split_group(rest)?;
+        let (name, userid) = split_group(rest)?;
+
+        Some(LogPlayer {
+            name: String::from(name),
+            userid: userid.parse().ok()?,
+            identity: PlayerIdentity::parse(identity).ok()?,
+            team: String::from(team),
+        })
+    }
+
+    /// The player's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The userid the server gave the player for this session.
+    pub fn userid(&self) -> i32 {
+        self.userid
+    }
+
+    /// Who the player is.
+    pub fn identity(&self) -> PlayerIdentity {
+        self.identity
+    }
+
+    /// The player's team, such as `CT`, `TERRORIST` or `Unassigned`. Can be empty.
+    pub fn team(&self) -> &str {
+        &self.team
+    }
+}
+
+impl LogEvent {
+    /// Parses a line of a server log. Returns None for lines that aren't one of the events.
+    ///
+    /// The `L 10/15/2026 - 20:14:03:` timestamp at the start of the line is optional and skipped.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::LogEvent;
+    ///
+    /// let line = r#""Robin<12><STEAM_1:1:221495335><>" connected, address "10.0.0.2:27005""#;
+    /// let Some(LogEvent::Connected { player, address }) = LogEvent::parse(line) else {
+    ///     panic!("not a connection");
+    /// };
+    ///
+    /// assert_eq!(player.identity().steamid().unwrap().steam64(), 76561198403256399);
+    /// assert_eq!(address.unwrap(), "10.0.0.2:27005");
+    ///
+    /// let line = r#""Console<0><Console><Console>" say "map changes in 5 minutes""#;
+    /// assert!(matches!(LogEvent::parse(line), Some(LogEvent::Say { team: false, .. })));
+    ///
+    /// assert_eq!(LogEvent::parse(r#"World triggered "Round_Start""#), None);
+    /// ```
+    pub fn parse(line: &str) -> Option<LogEvent> {
+        let line = line.trim_end();
+        let (player, rest) = split_player(&line[line.find('"')?..])?;
+        let rest = skip_position(rest);
+
+        if let Some(rest) = rest.strip_prefix("connected, address ") {
+            let address = quoted(rest).filter(|address| !address.is_empty());
+
+            Some(LogEvent::Connected {
+                player,
+                address: address.map(String::from),
+            })
+        } else if rest.starts_with("entered the game") {
+            Some(LogEvent::Entered { player })
+        } else if let Some(rest) = rest.strip_prefix("killed ") {
+            let (victim, rest) = split_player(rest)?;
+            let rest = skip_position(rest).strip_prefix("with ")?;
+            let weapon = quoted(rest)?;
+            let properties = &rest[rest.rfind('"')?..];
+
+            Some(LogEvent::Killed {
+                killer: player,
+                victim,
+                weapon: String::from(weapon),
+                headshot: properties.contains("headshot"),
+            })
+        } else if let Some(rest) = rest.strip_prefix("say_team ") {
+            Some(LogEvent::Say {
+                player,
+                message: String::from(quoted(rest)?),
+                team: true,
+            })
+        } else if let Some(rest) = rest.strip_prefix("say ") {
+            Some(LogEvent::Say {
+                player,
+                message: String::from(quoted(rest)?),
+                team: false,
+            })
+        } else {
+            let rest = rest.strip_prefix("disconnected")?;
+
+            Some(LogEvent::Disconnected {
+                player,
+                reason: quoted(rest).map(String::from),
+            })
+        }
+    }
+}
+
+/// Splits a trailing `<group>` off `input`.
+fn split_group(input: &str) -> Option<(&str, &str)> {
+    input.strip_suffix('>')?.rsplit_once('<')
+}
+
+/// Splits a quoted player off the start of `input`, returning the rest after the space.
+///
+/// Names can contain `>"`, so every place the player could end is tried.
+fn split_player(input: &str) -> Option<(LogPlayer, &str)> {
+    let body = input.strip_prefix('"')?;
+
+    body.match_indices(">\"").find_map(|(end, _)| {
+        let player = LogPlayer::parse(&body[..end + 1])?;
+        let rest = &body[end + 2..];
+
+        Some((player, rest.strip_prefix(' ').unwrap_or(rest)))
+    })
+}
+
+/// Skips the `[x y z]` position newer games log after a player.
+fn skip_position(input: &str) -> &str {
+    match input
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once("] "))
+    {
+        Some((_, rest)) => rest,
+        None => input,
+    }
+}
+
+/// The text between the first and last quote of `input`.
+fn quoted(input: &str) -> Option<&str> {
+    let start = input.find('"')? + 1;
+    let end = input.rfind('"')?;
+
+    input.get(start..end)
+}
@@ -0,0 +1,222 @@
+use crate::{SteamIdFormat, Type, Universe};
+
+const STEAM64: u8 = 1 << 0;
+const STEAM2: u8 = 1 << 1;
+const STEAM3: u8 = 1 << 2;
+const PROFILE_URL: u8 = 1 << 3;
+const GROUP_URL: u8 = 1 << 4;
+const INVITE_URL: u8 = 1 << 5;
+
+/// Controls how forgiving [`SteamID::parse_with`](crate::SteamID::parse_with) is.
+///
+/// [`ParseOptions::new`] behaves exactly like [`SteamID::parse`](crate::SteamID::parse).
+/// [`ParseOptions::lenient`] and [`ParseOptions::strict`] are starting points
+/// for messy and suspicious input, and every setting can be changed from there.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{ParseErrorKind, ParseOptions, SteamID, Type, Universe};
+///
+/// let lenient = ParseOptions::lenient();
+/// let steamid = SteamID::parse_with("  steam_0:1:221495335\n", &lenient).unwrap();
+/// assert_eq!(steamid.steam64(), 76561198403256399);
+///
+/// let strict = ParseOptions::strict().types(&[Type::Individual]);
+/// let error = SteamID::parse_with("[g:1:4]", &strict).unwrap_err();
+/// assert_eq!(error.kind(), ParseErrorKind::TypeNotAllowed);
+///
+/// let error = SteamID::parse_with("STEAM_0:1:0221495335", &strict).unwrap_err();
+/// assert_eq!(error.kind(), ParseErrorKind::LeadingZero);
+/// assert_eq!(error.span(), 10..20);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct ParseOptions {
+    pub(crate) trim: bool,
+    pub(crate) case_insensitive: bool,
+    pub(crate) leading_zeros: bool,
+    pub(crate) zero_account_id: bool,
+    pub(crate) short_steam64: bool,
+    formats: u8,
+    universes: [u64; 4],
+    types: u16,
+}
+
+impl ParseOptions {
+    /// The options [`SteamID::parse`](crate::SteamID::parse) uses.
+    ///
+    /// Every format, universe and type is accepted, but the input has to be exact:
+    /// no surrounding whitespace and `STEAM_` and URLs in the right case.
+    /// Leading zeros are allowed, zero account ids are not.
+    pub const fn new() -> Self {
+        Self {
+            trim: false,
+            case_insensitive: false,
+            leading_zeros: true,
+            zero_account_id: false,
+            short_steam64: false,
+            formats: STEAM64 | STEAM2 | STEAM3 | PROFILE_URL | GROUP_URL | INVITE_URL,
+            universes: [u64::MAX; 4],
+            types: u16::MAX,
+        }
+    }
+
+    /// Like [`ParseOptions::new`], but trims whitespace and ignores case.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use scream_id::{ParseOptions, SteamID};
+    ///
+    /// let pasted = " https://SteamCommunity.com/profiles/76561198403256399/\n";
+    /// let steamid = SteamID::parse_with(pasted, &ParseOptions::lenient()).unwrap();
+    ///
+    /// assert_eq!(steamid.steam64(), 76561198403256399);
+    /// assert!(SteamID::parse(pasted).is_err());
+    /// ```
+    pub const fn lenient() -> Self {
+        Self::new().trim(true).case_insensitive(true)
+    }
+
+    /// Only accepts SteamID64, Steam2 and Steam3 IDs in the public universe,
+    /// without leading zeros.
+    pub const fn strict() -> Self {
+        Self::new()
+            .leading_zeros(false)
+            .allow_profile_url(false)
+            .allow_group_url(false)
+            .allow_invite_url(false)
+            .universes(&[Universe::Public])
+    }
+
+    /// Whether whitespace around the input is ignored.
+    pub const fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Whether prefixes like `STEAM_`, URLs and invite codes are matched ignoring case.
+    ///
+    /// Steam3 type letters are always case sensitive, since `g` and `G` are different types.
+    pub const fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Whether numbers may start with a zero, like `STEAM_0:1:0221495335`.
+    pub const fn leading_zeros(mut self, leading_zeros: bool) -> Self {
+        self.leading_zeros = leading_zeros;
+        self
+    }
+
+    /// Whether a SteamID with account id 0 is accepted.
+    pub const fn zero_account_id(mut self, zero_account_id: bool) -> Self {
+        self.zero_account_id = zero_account_id;
+        self
+    }
+
+    /// Whether SteamID64s with fewer than 17 digits are accepted. Those are SteamIDs in
+    /// universe 0, which Steam doesn't use, and short numbers are far more likely to be
+    /// something else, so they're rejected by default.
+    pub const fn short_steam64(mut self, short_steam64: bool) -> Self {
+        self.short_steam64 = short_steam64;
+        self
+    }
+
+    /// Whether SteamID64s like `76561198403256399` are accepted.
+    pub const fn allow_steam64(self, allow: bool) -> Self {
+        self.allow_format(STEAM64, allow)
+    }
+
+    /// Whether Steam2 IDs like `STEAM_0:1:221495335` are accepted.
+    pub const fn allow_steam2(self, allow: bool) -> Self {
+        self.allow_format(STEAM2, allow)
+    }
+
+    /// Whether Steam3 IDs like `[U:1:442990671]` are accepted.
+    pub const fn allow_steam3(self, allow: bool) -> Self {
+        self.allow_format(STEAM3, allow)
+    }
+
+    /// Whether profile URLs like `https://steamcommunity.com/profiles/76561198403256399`
+    /// are accepted.
+    pub const fn allow_profile_url(self, allow: bool) -> Self {
+        self.allow_format(PROFILE_URL, allow)
+    }
+
+    /// Whether group URLs like `https://steamcommunity.com/gid/103582791429521412`
+    /// are accepted.
+    pub const fn allow_group_url(self, allow: bool) -> Self {
+        self.allow_format(GROUP_URL, allow)
+    }
+
+    /// Whether friend invite links like `https://s.team/p/cfbf-hwkc` are accepted.
+    pub const fn allow_invite_url(self, allow: bool) -> Self {
+        self.allow_format(INVITE_URL, allow)
+    }
+
+    /// Only accepts SteamIDs in one of these universes.
+    pub const fn universes(mut self, universes: &[Universe]) -> Self {
+        self.universes = [0; 4];
+
+        let mut i = 0;
+        while i < universes.len() {
+            let universe = (universes[i].as_u32() & 0xFF) as usize;
+            self.universes[universe / 64] |= 1 << (universe % 64);
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Only accepts SteamIDs of one of these types.
+    pub const fn types(mut self, types: &[Type]) -> Self {
+        self.types = 0;
+
+        let mut i = 0;
+        while i < types.len() {
+            self.types |= 1 << (types[i].as_u32() & 0xF);
+            i += 1;
+        }
+
+        self
+    }
+
+    const fn allow_format(mut self, format: u8, allow: bool) -> Self {
+        if allow {
+            self.formats |= format;
+        } else {
+            self.formats &= !format;
+        }
+
+        self
+    }
+
+    pub(crate) const fn allows_format(&self, format: SteamIdFormat) -> bool {
+        let format = match format {
+            SteamIdFormat::Steam64 => STEAM64,
+            SteamIdFormat::Steam2 { .. } => STEAM2,
+            SteamIdFormat::Steam3 { .. } => STEAM3,
+            SteamIdFormat::ProfileUrl => PROFILE_URL,
+            SteamIdFormat::GroupUrl => GROUP_URL,
+            SteamIdFormat::InviteUrl => INVITE_URL,
+        };
+
+        self.formats & format != 0
+    }
+
+    pub(crate) const fn allows_universe(&self, universe: Universe) -> bool {
+        let universe = (universe.as_u32() & 0xFF) as usize;
+        self.universes[universe / 64] & 1 << (universe % 64) != 0
+    }
+
+    pub(crate) const fn allows_type(&self, type_: Type) -> bool {
+        self.types & 1 << (type_.as_u32() & 0xF) != 0
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
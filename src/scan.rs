@@ -0,0 +1,257 @@
+use std::{collections::HashSet, ops::Range};
+
+use crate::{community::strip_scheme, strip_prefix, ParseOptions, SteamID, SteamIdFormat};
+
+/// Controls what [`scan_with`] finds.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{scan_with, ScanOptions};
+///
+/// let text = "76561198403256399 is [U:1:442990671], not 12345678901234567";
+///
+/// assert_eq!(scan_with(text, &ScanOptions::new()).count(), 3);
+/// assert_eq!(scan_with(text, &ScanOptions::new().valid_only(true)).count(), 2);
+/// assert_eq!(scan_with(text, &ScanOptions::new().valid_only(true).dedupe(true)).count(), 1);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct ScanOptions {
+    parse: ParseOptions,
+    dedupe: bool,
+    valid_only: bool,
+}
+
+impl ScanOptions {
+    /// The options [`scan`] uses. Everything is reported, and `STEAM_` and URLs
+    /// are found in any case.
+    pub const fn new() -> Self {
+        Self {
+            parse: ParseOptions::new().case_insensitive(true),
+            dedupe: false,
+            valid_only: false,
+        }
+    }
+
+    /// The options each candidate is parsed with, to limit the formats, universes
+    /// and types that are found.
+    pub const fn parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse = options;
+        self
+    }
+
+    /// Whether a SteamID is only reported the first time it's found,
+    /// even if it's written in another format later.
+    pub const fn dedupe(mut self, dedupe: bool) -> Self {
+        self.dedupe = dedupe;
+        self
+    }
+
+    /// Whether SteamIDs that fail [`SteamID::is_valid`] are skipped.
+    /// Weeds out numbers that only look like SteamID64s.
+    pub const fn valid_only(mut self, valid_only: bool) -> Self {
+        self.valid_only = valid_only;
+        self
+    }
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A SteamID found in text by [`scan`].
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Match<'a> {
+    steamid: SteamID,
+    format: SteamIdFormat,
+    text: &'a str,
+    start: usize,
+}
+
+impl<'a> Match<'a> {
+    /// The SteamID that was found.
+    pub fn steamid(&self) -> SteamID {
+        self.steamid
+    }
+
+    /// The format the SteamID was written in.
+    pub fn format(&self) -> SteamIdFormat {
+        self.format
+    }
+
+    /// The byte range of the SteamID in the text.
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+
+    /// The SteamID as it was written in the text.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+}
+
+/// An iterator over the SteamIDs in a text, created by [`scan`] and [`scan_with`].
+#[derive(Debug, Clone)]
+pub struct Scan<'a> {
+    text: &'a str,
+    position: usize,
+    options: ScanOptions,
+    seen: HashSet<SteamID>,
+}
+
+/// Finds every SteamID in a text, in any format [`SteamID::parse`] accepts.
+///
+/// A SteamID has to stand on its own: `x76561198403256399` or `STEAM_0:1:2abc` aren't found.
+/// SteamID64s need 17 to 20 digits, so other numbers in the text aren't taken for one.
+/// Vanity URLs are skipped, since they don't have a SteamID in them.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{scan, SteamIdFormat};
+///
+/// let text = "reported steam_0:1:221495335 (https://steamcommunity.com/profiles/76561198403256399).";
+/// let matches: Vec<_> = scan(text).collect();
+///
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!(matches[0].as_str(), "steam_0:1:221495335");
+/// assert_eq!(matches[0].span(), 9..28);
+/// assert_eq!(matches[1].format(), SteamIdFormat::ProfileUrl);
+/// assert_eq!(matches[1].as_str(), "https://steamcommunity.com/profiles/76561198403256399");
+/// assert_eq!(matches[0].steamid(), matches[1].steamid());
+/// ```
+pub fn scan(text: &str) -> Scan<'_> {
+    scan_with(text, &ScanOptions::new())
+}
+
+/// Finds every SteamID in a text like [`scan`], with the given options.
+pub fn scan_with<'a>(text: &'a str, options: &ScanOptions) -> Scan<'a> {
+    Scan {
+        text,
+        position: 0,
+        options: *options,
+        seen: HashSet::new(),
+    }
+}
+
+impl<'a> Iterator for Scan<'a> {
+    type Item = Match<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.text[self.position..].chars().next() {
+            let start = self.position;
+
+            let Some((steamid, format, end)) = self.match_at(start) else {
+                self.position += c.len_utf8();
+                continue;
+            };
+
+            self.position = end;
+
+            if self.options.valid_only && !steamid.is_valid() {
+                continue;
+            }
+
+            if self.options.dedupe && !self.seen.insert(steamid) {
+                continue;
+            }
+
+            return Some(Match {
+                steamid,
+                format,
+                text: &self.text[start..end],
+                start,
+            });
+        }
+
+        None
+    }
+}
+
+impl Scan<'_> {
+    /// Tries to read a SteamID starting at byte `start`, returning where it ends.
+    fn match_at(&self, start: usize) -> Option<(SteamID, SteamIdFormat, usize)> {
+        let options = &self.options.parse;
+        let rest = &self.text[start..];
+        let bytes = rest.as_bytes();
+
+        let len = if bytes[0] == b'[' {
+            // The longest Steam3 ID is `[a:255:4294967295:1048575]`.
+            bytes.iter().take(32).position(|&b| b == b']')? + 1
+        } else if !self.word_starts_at(start) {
+            return None;
+        } else if bytes[0].is_ascii_digit() {
+            let len = count(bytes, |b| b.is_ascii_digit());
+
+            // Shorter numbers are SteamID64s too, but in text they're almost never meant as one.
+            if !(17..=20).contains(&len) {
+                return None;
+            }
+
+            len
+        } else if let Some(ids) = strip_prefix(bytes, "STEAM_", options) {
+            // A colon after a Steam2 ID is punctuation.
+            let ids = &ids[..count(ids, |b| b.is_ascii_digit() || b == b':')];
+            "STEAM_".len() + ids.iter().rposition(|&b| b != b':')? + 1
+        } else if is_url(bytes, options) {
+            let url = &rest[..rest.find(is_url_end).unwrap_or(rest.len())];
+            url.trim_end_matches(|c| ".,;:!?)]}".contains(c)).len()
+        } else {
+            return None;
+        };
+
+        self.parse(start, len)
+    }
+
+    /// Parses `len` bytes from `start`, unless they're followed by more of a word.
+    fn parse(&self, start: usize, len: usize) -> Option<(SteamID, SteamIdFormat, usize)> {
+        let end = start + len;
+
+        if !self.word_ends_at(end) {
+            return None;
+        }
+
+        let input = &self.text.as_bytes()[start..end];
+        let (steamid, format) = SteamID::parse_untrimmed(input, &self.options.parse).ok()?;
+
+        Some((steamid, format, end))
+    }
+
+    /// Returns true if the character before byte `at` isn't part of a word.
+    fn word_starts_at(&self, at: usize) -> bool {
+        self.text[..at]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !is_word(c))
+    }
+
+    /// Returns true if the character at byte `at` isn't part of a word.
+    fn word_ends_at(&self, at: usize) -> bool {
+        self.text[at..].chars().next().is_none_or(|c| !is_word(c))
+    }
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Counts how many bytes at the start of `bytes` match.
+fn count(bytes: &[u8], matches: impl Fn(u8) -> bool) -> usize {
+    bytes.iter().take_while(|&&b| matches(b)).count()
+}
+
+/// Returns true if `bytes` starts like a Steam Community URL or friend invite link.
+fn is_url(bytes: &[u8], options: &ParseOptions) -> bool {
+    let url = strip_scheme(bytes, options);
+    let url = strip_prefix(url, "www.", options).unwrap_or(url);
+
+    strip_prefix(url, "steamcommunity.com/", options).is_some()
+        || strip_prefix(url, "s.team/p/", options).is_some()
+}
+
+/// Returns true for characters that end a URL in running text.
+fn is_url_end(c: char) -> bool {
+    c.is_whitespace() || "<>\"'`".contains(c)
+}
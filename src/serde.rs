@@ -0,0 +1,227 @@
+//! Serde support, enabled with the `serde` feature.
+//!
+//! A [`SteamID`] serializes as a SteamID64 string for human readable formats like JSON,
+//! since JavaScript can't hold a SteamID64 in a number, and as a `u64` everywhere else.
+//! Deserializing human readable formats accepts a `u64` or any text [`SteamID::parse`] accepts.
+//! Other formats can only be read back in the shape they were written in.
+//!
+//! The modules here pick the wire format explicitly, for use with `#[serde(with = "...")]`.
+//! They all deserialize the same lenient way in human readable formats.
+//!
+//! # Examples:
+//!
+//! ```
+//! use scream_id::SteamID;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Ban {
+//!     steamid: SteamID,
+//!     #[serde(with = "scream_id::serde::as_steam3")]
+//!     admin: SteamID,
+//! }
+//!
+//! let ban: Ban = serde_json::from_str(
+//!     r#"{"steamid": "STEAM_0:1:221495335", "admin": 76561197960287930}"#,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&ban).unwrap(),
+//!     r#"{"steamid":"76561198403256399","admin":"[U:1:22202]"}"#
+//! );
+//! ```
+//!
+//! Formats that aren't human readable, like bincode, store a plain `u64`:
+//!
+//! ```
+//! use scream_id::SteamID;
+//! use serde::{Deserialize, Serialize};
+//!
+//! let steamid = SteamID::new("[U:1:442990671]").unwrap();
+//! let bytes = bincode::serialize(&steamid).unwrap();
+//!
+//! assert_eq!(bytes, 76561198403256399u64.to_le_bytes());
+//! assert_eq!(bincode::deserialize::<SteamID>(&bytes).unwrap(), steamid);
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Ban {
+//!     #[serde(with = "scream_id::serde::as_u64")]
+//!     steamid: SteamID,
+//!     #[serde(with = "scream_id::serde::as_steam3")]
+//!     admin: SteamID,
+//! }
+//!
+//! let ban = Ban { steamid, admin: steamid };
+//! let bytes = bincode::serialize(&ban).unwrap();
+//! assert_eq!(bincode::deserialize::<Ban>(&bytes).unwrap(), ban);
+//! ```
+//!
+//! The [`Universe`], [`Type`] and [`Instance`] enums are stored as their raw numbers:
+//!
+//! ```
+//! use scream_id::{Type, Universe};
+//!
+//! assert_eq!(serde_json::to_string(&Universe::Public).unwrap(), "1");
+//! assert_eq!(serde_json::from_str::<Type>("12").unwrap(), Type::Other(12));
+//! ```
+
+use std::fmt;
+
+use ::serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{ChatFlags, Instance, SteamID, Type, Universe};
+
+impl Serialize for SteamID {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            as_string64::serialize(self, serializer)
+        } else {
+            as_u64::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SteamID {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_number(deserializer)
+    }
+}
+
+struct SteamIDVisitor;
+
+impl Visitor<'_> for SteamIDVisitor {
+    type Value = SteamID;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a SteamID64 number or a SteamID string")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<SteamID, E> {
+        SteamID::try_from(value).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<SteamID, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<SteamID, E> {
+        SteamID::parse(value).map_err(E::custom)
+    }
+}
+
+// Formats that aren't human readable, like bincode, don't say what type comes next,
+// so those have to be asked for the type the matching `serialize` wrote.
+
+fn deserialize_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SteamID, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(SteamIDVisitor)
+    } else {
+        deserializer.deserialize_u64(SteamIDVisitor)
+    }
+}
+
+fn deserialize_text<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SteamID, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(SteamIDVisitor)
+    } else {
+        deserializer.deserialize_str(SteamIDVisitor)
+    }
+}
+
+/// Serializes a [`SteamID`] as a SteamID64 string, e.g. `"76561198403256399"`.
+pub mod as_string64 {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::SteamID;
+
+    pub fn serialize<S: Serializer>(steamid: &SteamID, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&steamid.steam64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SteamID, D::Error> {
+        super::deserialize_text(deserializer)
+    }
+}
+
+/// Serializes a [`SteamID`] as a SteamID64 number, e.g. `76561198403256399`.
+pub mod as_u64 {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::SteamID;
+
+    pub fn serialize<S: Serializer>(steamid: &SteamID, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(steamid.steam64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SteamID, D::Error> {
+        super::deserialize_number(deserializer)
+    }
+}
+
+/// Serializes a [`SteamID`] as a Steam3 string, e.g. `"[U:1:442990671]"`.
+pub mod as_steam3 {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::SteamID;
+
+    pub fn serialize<S: Serializer>(steamid: &SteamID, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&steamid.render_as_steam3())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SteamID, D::Error> {
+        super::deserialize_text(deserializer)
+    }
+}
+
+/// Serializes a [`SteamID`] as a Steam2 string, e.g. `"STEAM_0:1:221495335"`.
+///
+/// Serializing fails for SteamIDs that aren't individual accounts.
+pub mod as_steam2 {
+    use ::serde::{ser::Error, Deserializer, Serializer};
+
+    use crate::SteamID;
+
+    pub fn serialize<S: Serializer>(steamid: &SteamID, serializer: S) -> Result<S::Ok, S::Error> {
+        match steamid.render_as_steam2() {
+            Some(steam2) => serializer.serialize_str(&steam2),
+            None => Err(S::Error::custom(format_args!(
+                "{:#} can't be rendered as Steam2",
+                steamid
+            ))),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SteamID, D::Error> {
+        super::deserialize_text(deserializer)
+    }
+}
+
+// The enums go over the wire as their raw values so unknown values survive the trip.
+
+macro_rules! raw_u32_serde {
+    ($type:ty, $from:expr, $into:expr) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u32($into(self))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                u32::deserialize(deserializer).map($from)
+            }
+        }
+    };
+}
+
+raw_u32_serde!(Universe, Universe::from_u32, Universe::as_u32);
+raw_u32_serde!(Type, Type::from_u32, Type::as_u32);
+raw_u32_serde!(Instance, Instance::from_u32, Instance::as_u32);
+raw_u32_serde!(ChatFlags, ChatFlags::from_instance, ChatFlags::bits);
@@ -0,0 +1,257 @@
+use std::time::Duration;
+
+use crate::PlayerIdentity;
+
+/// A row of the player table printed by the `status` console command.
+///
+/// Source 1 games print the player's SteamID and CS2 doesn't, so [`StatusPlayer::identity`]
+/// is only None for CS2 players that aren't bots.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct StatusPlayer {
+    userid: u32,
+    name: String,
+    identity: Option<PlayerIdentity>,
+    time: Option<Duration>,
+    ping: Option<u32>,
+    loss: Option<u32>,
+    state: String,
+    address: Option<String>,
+}
+
+impl StatusPlayer {
+    /// The userid the server gave the player for this session.
+    pub fn userid(&self) -> u32 {
+        self.userid
+    }
+
+    /// The player's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Who the player is, if the table says.
+    pub fn identity(&self) -> Option<PlayerIdentity> {
+        self.identity
+    }
+
+    /// How long the player has been connected. None for bots.
+    pub fn time(&self) -> Option<Duration> {
+        self.time
+    }
+
+    /// The player's ping in milliseconds. None for bots in Source 1 games.
+    pub fn ping(&self) -> Option<u32> {
+        self.ping
+    }
+
+    /// The player's packet loss in percent. None for bots in Source 1 games.
+    pub fn loss(&self) -> Option<u32> {
+        self.loss
+    }
+
+    /// The state of the player's connection, such as `active` or `spawning`.
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// The player's IP address and port, or `loopback` for the host of a listen server.
+    /// None for bots.
+    pub fn address(&self) -> Option<&str> {
+        self.address.as_deref()
+    }
+}
+
+/// Parses the player table out of the output of the `status` console command.
+///
+/// Understands the Source 1 layout, with a `# userid name uniqueid ...` header and the
+/// extra slot column CS:GO adds, and the CS2 layout, with an `id time ping loss ...` header
+/// under `---------players--------`. Other lines are skipped, so the whole output can be
+/// passed in.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{parse_status, PlayerIdentity};
+///
+/// let output = r#"hostname: Scream
+/// ## userid name                uniqueid            connected ping loss state  adr
+/// ##      2 "Robin"             [U:1:442990671]     05:12       45    0 active 10.0.0.2:27005
+/// ##      3 "Ted"               BOT                                     active
+/// "#;
+///
+/// let players = parse_status(output);
+///
+/// assert_eq!(players.len(), 2);
+/// assert_eq!(players[0].name(), "Robin");
+/// assert_eq!(players[0].identity().unwrap().steamid().unwrap().steam64(), 76561198403256399);
+/// assert_eq!(players[0].time().unwrap().as_secs(), 312);
+/// assert_eq!(players[0].ping(), Some(45));
+/// assert_eq!(players[0].address(), Some("10.0.0.2:27005"));
+/// assert_eq!(players[1].identity(), Some(PlayerIdentity::Bot));
+/// assert_eq!(players[1].state(), "active");
+/// ```
+///
+/// CS:GO and CS2:
+///
+/// ```
+/// use scream_id::{parse_status, PlayerIdentity};
+///
+/// let csgo = r#"# userid name uniqueid connected ping loss state rate adr
+/// ##  2 1 "Robin" STEAM_1:1:221495335 1:05:12 45 0 active 196608 10.0.0.2:27005
+/// #end
+/// "#;
+///
+/// let players = parse_status(csgo);
+/// assert_eq!(players[0].userid(), 2);
+/// assert_eq!(players[0].time().unwrap().as_secs(), 3912);
+///
+/// let cs2 = r#"---------players--------
+///   id     time ping loss      state   rate adr name
+/// 65535 [NoChan]    0    0 challenging      0unknown ''
+///     2    05:12   45    0     active 786432 10.0.0.2:27005 'Robin'
+///     3      BOT    0    0     active      0 'Ted'
+/// #end
+/// "#;
+///
+/// let players = parse_status(cs2);
+/// assert_eq!(players.len(), 2);
+/// assert_eq!(players[0].identity(), None);
+/// assert_eq!(players[0].loss(), Some(0));
+/// assert_eq!(players[1].identity(), Some(PlayerIdentity::Bot));
+/// ```
+pub fn parse_status(output: &str) -> Vec<StatusPlayer> {
+    let mut players = Vec::new();
+    let mut cs2_columns: Option<Vec<&str>> = None;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+
+        if trimmed == "#end" {
+            cs2_columns = None;
+        } else if trimmed.starts_with('#') {
+            players.extend(parse_source1_row(trimmed));
+        } else if let Some(columns) = &cs2_columns {
+            players.extend(parse_cs2_row(trimmed, columns));
+        } else if is_cs2_header(trimmed) {
+            cs2_columns = Some(trimmed.split_whitespace().collect());
+        }
+    }
+
+    players
+}
+
+/// Parses a row like `#  2 1 "Robin" STEAM_1:1:221495335 05:12 45 0 active 196608 10.0.0.2:27005`.
+/// The header and footer lines don't have a quoted name, so they're skipped.
+fn parse_source1_row(line: &str) -> Option<StatusPlayer> {
+    let name_start = line.find('"')?;
+    let name_end = line.rfind('"')?;
+    let name = line.get(name_start + 1..name_end)?;
+
+    // CS:GO puts a slot number after the userid.
+    let userid = line[1..name_start]
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+
+    let mut columns = line[name_end + 1..].split_whitespace();
+    let identity = PlayerIdentity::parse(columns.next()?).ok()?;
+
+    let mut player = StatusPlayer {
+        userid,
+        name: String::from(name),
+        identity: Some(identity),
+        time: None,
+        ping: None,
+        loss: None,
+        state: String::new(),
+        address: None,
+    };
+
+    // Bots leave out the time, ping, loss and address, so go by what each column looks like.
+    for column in columns {
+        if let Some(time) = parse_time(column) {
+            player.time = Some(time);
+        } else if let Ok(number) = column.parse() {
+            // The rate after the state isn't kept.
+            if player.state.is_empty() && player.ping.is_none() {
+                player.ping = Some(number);
+            } else if player.state.is_empty() && player.loss.is_none() {
+                player.loss = Some(number);
+            }
+        } else if player.state.is_empty() {
+            player.state = String::from(column);
+        } else {
+            player.address = Some(String::from(column));
+        }
+    }
+
+    Some(player)
+}
+
+/// Returns true for the CS2 header, `id time ping loss state rate adr name`.
+fn is_cs2_header(line: &str) -> bool {
+    let mut columns = line.split_whitespace();
+    columns.next() == Some("id") && columns.any(|column| column == "name")
+}
+
+/// Parses a row like `2    05:12   45    0     active 786432 10.0.0.2:27005 'Robin'`,
+/// with the values in the order of the header's `columns`.
+fn parse_cs2_row(line: &str, columns: &[&str]) -> Option<StatusPlayer> {
+    let name_start = line.find('\'')?;
+    let name_end = line.rfind('\'')?;
+    let name = line.get(name_start + 1..name_end)?;
+
+    let mut player = StatusPlayer {
+        userid: 0,
+        name: String::from(name),
+        identity: None,
+        time: None,
+        ping: None,
+        loss: None,
+        state: String::new(),
+        address: None,
+    };
+
+    let values = line[..name_start].split_whitespace();
+    let mut has_id = false;
+
+    for (&column, value) in columns.iter().zip(values) {
+        match column {
+            "id" => {
+                player.userid = value.parse().ok()?;
+                has_id = true;
+            }
+            // Bots have `BOT` instead of a time. Slots nobody is on have `[NoChan]`.
+            "time" if value == "BOT" => player.identity = Some(PlayerIdentity::Bot),
+            "time" => player.time = Some(parse_time(value)?),
+            "ping" => player.ping = value.parse().ok(),
+            "loss" => player.loss = value.parse().ok(),
+            "state" => player.state = String::from(value),
+            "adr" => player.address = Some(String::from(value)),
+            "steamid" | "uniqueid" => player.identity = PlayerIdentity::parse(value).ok(),
+            _ => {}
+        }
+    }
+
+    has_id.then_some(player)
+}
+
+/// Parses a connection time like `05:12` or `1:05:12`.
+fn parse_time(input: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    let mut parts = 0;
+
+    for part in input.split(':') {
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        seconds = seconds * 60 + part.parse::<u64>().ok()?;
+        parts += 1;
+    }
+
+    (2..=3)
+        .contains(&parts)
+        .then(|| Duration::from_secs(seconds))
+}
@@ -0,0 +1,200 @@
+use std::{fmt, str::FromStr};
+
+use crate::{ChatFlags, SteamID, Type, TypedParseError, Universe, WrongTypeError};
+
+/// Implements what every typed wrapper shares: conversions to and from [`SteamID`],
+/// parsing and display.
+macro_rules! typed_id {
+    ($name:ident, $expected:literal, $check:expr) => {
+        impl $name {
+            /// The wrapped SteamID.
+            pub const fn steamid(&self) -> SteamID {
+                self.0
+            }
+
+            /// The 32-bit account id.
+            pub const fn account_id(&self) -> u32 {
+                self.0.account_id()
+            }
+
+            /// The SteamID as a SteamID64.
+            pub const fn steam64(&self) -> u64 {
+                self.0.steam64()
+            }
+
+            /// Renders the ID as a Steam3 string.
+            pub fn render_as_steam3(&self) -> String {
+                self.0.render_as_steam3()
+            }
+        }
+
+        impl TryFrom<SteamID> for $name {
+            type Error = WrongTypeError;
+
+            fn try_from(steamid: SteamID) -> Result<Self, Self::Error> {
+                let check: fn(&SteamID) -> bool = $check;
+
+                match check(&steamid) {
+                    true => Ok(Self(steamid)),
+                    false => Err(WrongTypeError::new(steamid, $expected)),
+                }
+            }
+        }
+
+        impl From<$name> for SteamID {
+            fn from(id: $name) -> SteamID {
+                id.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = TypedParseError;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                let steamid = SteamID::parse(input).map_err(TypedParseError::Parse)?;
+                Self::try_from(steamid).map_err(TypedParseError::WrongType)
+            }
+        }
+
+        impl fmt::Display for $name {
+            /// Formats the same way [`SteamID`] does.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+/// The SteamID of an individual account, a player.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::{IndividualId, SteamID};
+///
+/// let player: IndividualId = "[U:1:442990671]".parse().unwrap();
+///
+/// assert_eq!(player.render_as_steam2(), "STEAM_0:1:221495335");
+/// assert_eq!(SteamID::from(player).steam64(), 76561198403256399);
+///
+/// assert!("[g:1:4]".parse::<IndividualId>().is_err());
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct IndividualId(SteamID);
+
+typed_id!(IndividualId, "an individual account", |steamid| {
+    steamid.account_type() == Type::Individual
+});
+
+impl IndividualId {
+    /// Builds the ID of an individual account in the public universe.
+    pub const fn from_account_id(account_id: u32) -> Self {
+        Self(SteamID::from_individual_account_id(account_id))
+    }
+
+    /// Renders the ID as a Steam2 string.
+    pub fn render_as_steam2(&self) -> String {
+        self.0.render_as_steam2().unwrap()
+    }
+
+    /// The Steam Community profile URL.
+    pub fn profile_url(&self) -> String {
+        self.0.profile_url().unwrap()
+    }
+
+    /// The friend invite code. Returns None outside the public universe.
+    pub fn to_invite_code(&self) -> Option<String> {
+        self.0.to_invite_code()
+    }
+
+    /// The Counter-Strike friend code. Returns None outside the public universe.
+    pub fn to_csgo_friend_code(&self) -> Option<String> {
+        self.0.to_csgo_friend_code()
+    }
+}
+
+/// The SteamID of a clan, a Steam group.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::ClanId;
+///
+/// let group: ClanId = "103582791429521412".parse().unwrap();
+///
+/// assert_eq!(group.group_url(), "https://steamcommunity.com/gid/103582791429521412");
+/// assert_eq!(group.chat_id().render_as_steam3(), "[c:1:4]");
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct ClanId(SteamID);
+
+typed_id!(ClanId, "a clan", |steamid| steamid.account_type()
+    == Type::Clan);
+
+impl ClanId {
+    /// The Steam Community group URL.
+    pub fn group_url(&self) -> String {
+        self.0.group_url().unwrap()
+    }
+
+    /// The SteamID of the clan's group chat.
+    pub fn chat_id(&self) -> SteamID {
+        self.0.clan_to_chat().unwrap()
+    }
+}
+
+/// The SteamID of a game server, persistent or anonymous.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::GameServerId;
+///
+/// let server: GameServerId = "[A:1:3751:7624]".parse().unwrap();
+///
+/// assert!(server.is_anonymous());
+/// assert!("[U:1:442990671]".parse::<GameServerId>().is_err());
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct GameServerId(SteamID);
+
+typed_id!(GameServerId, "a game server", |steamid| matches!(
+    steamid.account_type(),
+    Type::GameServer | Type::AnonGameServer
+));
+
+impl GameServerId {
+    /// Returns true for anonymous game servers, which get a new SteamID every time they log in.
+    pub fn is_anonymous(&self) -> bool {
+        self.0.account_type() == Type::AnonGameServer
+    }
+
+    /// The universe the game server is in.
+    pub const fn universe(&self) -> Universe {
+        self.0.universe()
+    }
+}
+
+/// The SteamID of a lobby, including matchmaking lobbies.
+///
+/// # Examples:
+///
+/// ```
+/// use scream_id::LobbyId;
+///
+/// let lobby: LobbyId = "109212290963734533".parse().unwrap();
+///
+/// assert!(!lobby.is_mms_lobby());
+/// assert!("[T:1:5]".parse::<LobbyId>().is_err());
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct LobbyId(SteamID);
+
+typed_id!(LobbyId, "a lobby", SteamID::is_lobby);
+
+impl LobbyId {
+    /// Returns true if the lobby was made by the matchmaking service.
+    pub const fn is_mms_lobby(&self) -> bool {
+        self.0.chat_flags().contains(ChatFlags::MMS_LOBBY)
+    }
+}